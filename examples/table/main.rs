@@ -6,6 +6,7 @@ use iced::{
 };
 
 use modav_core::repr::col_sheet::{CellRef, ColumnSheet, DataType};
+use std::borrow::Cow;
 
 use table::{Action, Motion, RawTable, Table};
 
@@ -97,6 +98,7 @@ impl App {
                         }
                     }
                 }
+                Action::PageLimit(limit) => self.limit = limit,
                 //action => println!("{action:#?} not set"),
                 _ => {}
             },
@@ -114,6 +116,7 @@ impl App {
         let content = Table::new(&self.sht)
             .height(Length::Shrink)
             .page_limit(self.limit)
+            .page_sizes(&[10, 25, 50, 100])
             .on_keypress(|key_press| {
                 if key_press.key == keyboard::Key::Named(keyboard::key::Named::Home) {
                     Some(Message::Test)
@@ -166,17 +169,17 @@ impl App {
     }
 }
 
-pub fn cell_to_string(cell: CellRef<'_>) -> String {
+pub fn cell_to_string(cell: CellRef<'_>) -> Cow<'_, str> {
     match cell {
-        CellRef::Text(value) => value.to_owned(),
-        CellRef::I32(value) => value.to_string(),
-        CellRef::U32(value) => value.to_string(),
-        CellRef::ISize(value) => value.to_string(),
-        CellRef::USize(value) => value.to_string(),
-        CellRef::F32(value) => value.to_string(),
-        CellRef::F64(value) => value.to_string(),
-        CellRef::Bool(value) => value.to_string(),
-        CellRef::None => "None".to_owned(),
+        CellRef::Text(value) => Cow::Borrowed(value),
+        CellRef::I32(value) => Cow::Owned(value.to_string()),
+        CellRef::U32(value) => Cow::Owned(value.to_string()),
+        CellRef::ISize(value) => Cow::Owned(value.to_string()),
+        CellRef::USize(value) => Cow::Owned(value.to_string()),
+        CellRef::F32(value) => Cow::Owned(value.to_string()),
+        CellRef::F64(value) => Cow::Owned(value.to_string()),
+        CellRef::Bool(value) => Cow::Owned(value.to_string()),
+        CellRef::None => Cow::Borrowed("None"),
     }
 }
 
@@ -203,8 +206,12 @@ impl RawTable for Wrapper {
         self.0.get_col(index).map(|column| column.kind())
     }
 
-    fn cell(&self, row: usize, column: usize) -> Option<String> {
-        self.0.get_cell(column, row).map(cell_to_string)
+    fn cell(&self, row: usize, column: usize) -> Option<Cow<'_, str>> {
+        // Missing values show the table placeholder instead
+        match self.0.get_cell(column, row)? {
+            CellRef::None => None,
+            cell => Some(cell_to_string(cell)),
+        }
     }
 
     fn is_empty(&self) -> bool {
@@ -219,14 +226,28 @@ impl RawTable for Wrapper {
             }
             DataType::U32 | DataType::USize => character.is_ascii_digit() || character == '_',
             DataType::F32 | DataType::F64 => {
-                character.is_ascii_digit() || character == '-' || character == '_'
+                character.is_ascii_digit() || matches!(character, '-' | '+' | '_' | '.' | 'e' | 'E')
             }
-            DataType::Bool => {
-                let chars = [
-                    't', 'T', 'r', 'R', 'u', 'U', 'e', 'E', 'f', 'F', 'a', 'A', 'l', 'L', 's', 'S',
-                ];
+            // Toggled as checkmarks instead
+            DataType::Bool => false,
+        }
+    }
+
+    fn column_validate(&self, kind: &Self::ColumnKind, value: &str) -> bool {
+        let value = value.replace('_', "");
+        let is_partial = value.is_empty() || value == "-";
 
-                chars.contains(&character)
+        match kind {
+            DataType::Text => true,
+            DataType::I32 => is_partial || value.parse::<i32>().is_ok(),
+            DataType::ISize => is_partial || value.parse::<isize>().is_ok(),
+            DataType::U32 => value.is_empty() || value.parse::<u32>().is_ok(),
+            DataType::USize => value.is_empty() || value.parse::<usize>().is_ok(),
+            // Completing the value lets "3." or "1e-" through as prefixes
+            DataType::F32 | DataType::F64 => format!("{value}0").parse::<f64>().is_ok(),
+            DataType::Bool => {
+                let value = value.to_lowercase();
+                "true".starts_with(&value) || "false".starts_with(&value)
             }
         }
     }
@@ -237,4 +258,12 @@ impl RawTable for Wrapper {
             _ => Horizontal::Right,
         }
     }
+
+    fn is_boolean(&self, kind: &Self::ColumnKind) -> bool {
+        matches!(kind, DataType::Bool)
+    }
+
+    fn is_numeric(&self, kind: &Self::ColumnKind) -> bool {
+        !matches!(kind, DataType::Text | DataType::Bool)
+    }
 }
@@ -8,11 +8,42 @@
 //!
 //! Functionality:
 //!
-//! - Shift + Arrow direction: Expands/shrinks current selection
+//! - Shift + Arrow direction: Extends the current selection from the cell it
+//!   started at, in any direction
 //! - Shift + Click: Starts a contiguous selection
-//! - Ctrl + Click: Starts a non-contiguous selection
+//! - Click + Drag: Drags out a contiguous selection, or moves the cell if it
+//!   was already selected
+//! - Ctrl + Click: Starts a non-contiguous selection, or removes an already
+//!   selected cell from it
+//! - Shift/Ctrl + Click on the numbering: Selects a range of rows or adds a
+//!   non-adjacent row
+//! - Shift/Ctrl + Click on the headers: Selects a range of columns or adds a
+//!   non-adjacent column
 //! - ArrowDown or Enter: Moves selection down
 //! - ArrowRight or Shift+Enter: Moves selection to the right.
+//! - Tab or Shift + Tab: Moves selection to the next or previous cell,
+//!   wrapping around rows.
+//! - PageDown or PageUp: Moves to the next or previous page.
+//! - Ctrl + Home or Ctrl + End: Moves to the first or last page, or with a
+//!   selection, selects the first or last cell of the page.
+//! - Home or End: Selects the first or last cell of the current row.
+//! - Shift + Home/End: Extends the current selection to those cells.
+//! - Double Click on a resize handle: Fits the column or row to its contents.
+//! - Alt + Arrow direction: Resizes the columns selected from the headers,
+//!   or the rows selected from the numbering.
+//! - Shift + Scroll: Scrolls the cells horizontally.
+//! - Ctrl + G: Focuses the goto page input.
+//! - Typing over a selected cell: Replaces the cell's contents.
+//! - Enter or Shift + Enter while editing: Commits the cell and selects the
+//!   cell below or above it.
+//! - Tab or Shift + Tab while editing: Commits the cell and edits the next or
+//!   previous cell, wrapping around rows.
+//! - Ctrl + A: Selects every cell on the page, then on every page.
+//! - Escape: Clears the current selection.
+//! - Ctrl + C: Copies the current selection as tab separated values.
+//! - Ctrl + X: Cuts the current selection, clearing its cells.
+//! - Delete or Backspace: Clears the cells of the current selection.
+//! - Ctrl + V: Pastes tab separated values starting at the current selection.
 
 use iced::{
     advanced::{
@@ -21,27 +52,59 @@ use iced::{
         mouse,
         renderer::Quad,
         text::{self, paragraph::Plain, LineHeight, Paragraph, Shaping, Wrapping},
-        widget::tree::{self, Tag, Tree},
+        widget::{
+            self,
+            tree::{self, Tag, Tree},
+            Operation,
+        },
         Widget,
     },
     alignment::{self, Horizontal, Vertical},
     event, Background, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size,
 };
+use lilt::Easing;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+mod operation;
+use operation::Request;
+pub use operation::{go_to_page, reveal_cell};
 
 mod state;
 use state::*;
 
 mod utils;
-pub use utils::{Action, KeyPress, Motion, RawTable, Selection};
+pub use utils::{
+    Action, Axis, CellTarget, EditState, EllipsisJump, KeyPress, Motion, NumericFormat, Overflow,
+    RawTable, Selection, SortOrder, TargetKind,
+};
 
 pub mod style;
-use style::{Catalog, Style, StyleFn};
+use style::{Catalog, CellStyle, Status, Style, StyleFn};
 
 type Cell<Renderer> = Plain<<Renderer as text::Renderer>::Paragraph>;
 
+/// A hook asked about the cell at a row and column.
+type CellFn<'a, T> = Box<dyn Fn(usize, usize) -> T + 'a>;
+
+/// Formats the text of a cell at a row and column for display.
+type FormatFn<'a> = Box<dyn Fn(&str, usize, usize) -> String + 'a>;
+
+/// Styles the cell at a row and column from its text.
+type CellStyleFn<'a> = Box<dyn Fn(usize, usize, &str) -> Option<CellStyle> + 'a>;
+
 const PAGINATION_ELLIPSIS: &str = "•••";
+const CHECKED_MARK: &str = "✓";
+const UNCHECKED_MARK: &str = "✗";
 /// The maximum number of items on a page
 const PAGE_LIMIT: usize = 25;
+/// The default spacing between the cells of a [`Table`].
+pub const CELL_GAP: f32 = 3.5;
+/// The default maximum height of the text of a [`Table`] cell.
+pub const MAX_CELL_HEIGHT: f32 = 45.0;
+/// The default maximum number of page buttons of a [`Table`].
+pub const PAGINATION_LIMIT: usize = 11;
 
 /// A table widget.
 pub struct Table<'a, Raw, Message, Theme, Renderer>
@@ -58,15 +121,68 @@ where
     height: Length,
     text_size: Option<Pixels>,
     font: Option<Renderer::Font>,
+    shaping: Shaping,
+    line_height: LineHeight,
     header_font: Option<Renderer::Font>,
     numbering_font: Option<Renderer::Font>,
+    header_text_size: Option<Pixels>,
+    numbering_text_size: Option<Pixels>,
+    numbering: bool,
+    show_column_kinds: bool,
     spacing: f32,
     padding: Padding,
     cell_padding: Padding,
     status: Option<String>,
+    status_left: Option<String>,
+    status_right: Option<String>,
+    arrow_keys_commit: bool,
+    double_click_edits: bool,
+    cursor_blink: bool,
+    read_only: bool,
+    loading: bool,
+    editable_columns: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    hidden_columns: Option<Box<dyn Fn(usize) -> bool + 'a>>,
+    editable_headers: bool,
+    bool_as_checkbox: bool,
+    highlight_hovered_row: bool,
+    searchable: bool,
+    sortable: bool,
+    sorted_by: Option<(usize, SortOrder)>,
+    page_sizes: Vec<usize>,
+    paginate: bool,
+    scrollbar_width: f32,
+    scroll_step: f32,
+    smooth_scrolling: Option<(f32, Easing)>,
+    resize_step: f32,
+    reset_scroll_on_page_change: bool,
+    keep_horizontal_scroll: bool,
+    submit_on_page_change: bool,
+    column_widths: Vec<Option<f32>>,
+    column_groups: Vec<(String, RangeInclusive<usize>)>,
+    frozen_columns: usize,
+    max_column_width: f32,
+    fill_columns: bool,
+    grid_line_width: f32,
+    cell_gap: f32,
+    max_cell_height: f32,
+    pagination_limit: usize,
+    ellipsis_jump: EllipsisJump,
+    overflow: Overflow,
+    max_lines: usize,
+    id: Option<widget::Id>,
     class: Theme::Class<'a>,
     on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
     on_keypress: Option<Box<dyn Fn(KeyPress) -> Option<Message> + 'a>>,
+    on_page_change: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_tooltip: Option<CellFn<'a, Option<String>>>,
+    cell_errors: Option<CellFn<'a, Option<String>>>,
+    format_cell: Option<FormatFn<'a>>,
+    cell_style: Option<CellStyleFn<'a>>,
+    footer: Option<Box<dyn Fn(usize) -> Option<String> + 'a>>,
+    column_alignment: Option<Box<dyn Fn(usize) -> Option<Horizontal> + 'a>>,
+    none_placeholder: String,
+    match_query: String,
+    numeric_format: NumericFormat,
 }
 
 impl<'a, Raw, Message, Theme, Renderer> Table<'a, Raw, Message, Theme, Renderer>
@@ -89,12 +205,65 @@ where
             padding: [10, 15].into(),
             cell_padding: [2, 5].into(),
             font: None,
+            shaping: Shaping::Advanced,
+            line_height: LineHeight::default(),
             header_font: None,
             numbering_font: None,
+            header_text_size: None,
+            numbering_text_size: None,
+            numbering: true,
+            show_column_kinds: true,
             spacing: 10.0,
             on_action: None,
             on_keypress: None,
+            on_page_change: None,
+            on_tooltip: None,
+            cell_errors: None,
+            format_cell: None,
+            cell_style: None,
+            footer: None,
+            column_alignment: None,
+            none_placeholder: String::new(),
+            match_query: String::new(),
+            numeric_format: NumericFormat::default(),
             status: None,
+            status_left: None,
+            status_right: None,
+            arrow_keys_commit: false,
+            double_click_edits: true,
+            cursor_blink: true,
+            read_only: false,
+            loading: false,
+            editable_columns: None,
+            hidden_columns: None,
+            editable_headers: true,
+            bool_as_checkbox: true,
+            highlight_hovered_row: true,
+            searchable: false,
+            sortable: false,
+            sorted_by: None,
+            page_sizes: Vec::new(),
+            paginate: true,
+            scrollbar_width: 6.0,
+            scroll_step: 5.0,
+            smooth_scrolling: None,
+            resize_step: 8.0,
+            reset_scroll_on_page_change: true,
+            keep_horizontal_scroll: false,
+            submit_on_page_change: true,
+            column_widths: Vec::new(),
+            column_groups: Vec::new(),
+            frozen_columns: 0,
+            max_column_width: f32::INFINITY,
+            fill_columns: false,
+            grid_line_width: 1.0,
+            cell_gap: CELL_GAP,
+            max_cell_height: MAX_CELL_HEIGHT,
+            pagination_limit: PAGINATION_LIMIT,
+            ellipsis_jump: EllipsisJump::default(),
+            overflow: Overflow::default(),
+            max_lines: 1,
+            id: None,
             class: Theme::default(),
         }
     }
@@ -113,8 +282,299 @@ where
 
     // 0 causes a weird issue
     /// Sets the maximum number of rows per page for the [`Table`].
+    ///
+    /// Ignored when pagination is disabled with [`Table::paginate`].
     pub fn page_limit(mut self, limit: usize) -> Self {
-        self.page_limit = limit.max(1);
+        if self.paginate {
+            self.page_limit = limit.max(1);
+        }
+        self
+    }
+
+    /// Sets whether the rows of the [`Table`] are split into pages.
+    ///
+    /// When disabled, every row is shown at once and the pagination, goto and
+    /// page size controls are hidden. Defaults to `true`.
+    pub fn paginate(mut self, paginate: bool) -> Self {
+        if !paginate {
+            self.page_limit = self.raw.height().max(1);
+        } else if !self.paginate {
+            self.page_limit = PAGE_LIMIT.min(self.raw.height());
+        }
+
+        self.paginate = paginate;
+        self
+    }
+
+    /// Sets the page sizes offered by the page size selector of the [`Table`].
+    ///
+    /// Choosing one publishes an [`Action::PageLimit`]. The selector is hidden
+    /// when no page sizes are given.
+    pub fn page_sizes(mut self, sizes: &[usize]) -> Self {
+        self.page_sizes = sizes.iter().copied().filter(|size| *size > 0).collect();
+        self
+    }
+
+    /// Sets whether the cells of the [`Table`] scroll back to the top left
+    /// when the page changes. Defaults to `true`.
+    pub fn reset_scroll_on_page_change(mut self, reset: bool) -> Self {
+        self.reset_scroll_on_page_change = reset;
+        self
+    }
+
+    /// Sets whether the horizontal scroll position of the [`Table`] is kept
+    /// when the page changes, even if
+    /// [`Table::reset_scroll_on_page_change`] is enabled. Defaults to `false`.
+    pub fn keep_horizontal_scroll(mut self, keep: bool) -> Self {
+        self.keep_horizontal_scroll = keep;
+        self
+    }
+
+    /// Sets whether the header or cell being edited is submitted when the
+    /// pagination or go-to input of the [`Table`] is used, as when clicking
+    /// away from it. Otherwise the edit is discarded. Defaults to `true`.
+    pub fn submit_on_page_change(mut self, submit: bool) -> Self {
+        self.submit_on_page_change = submit;
+        self
+    }
+
+    /// Sets the initial widths of the [`Table`] columns.
+    ///
+    /// Columns given `None`, or not given at all, are sized to their contents.
+    /// An explicit width narrower than the contents clips them.
+    pub fn column_widths(mut self, widths: impl Into<Vec<Option<f32>>>) -> Self {
+        self.column_widths = widths.into();
+        self
+    }
+
+    /// Sets the labelled groups of columns shown in a band above the headers.
+    ///
+    /// Each group spans the columns in its range and clicking it selects
+    /// them. Columns outside every group leave the band blank above them.
+    pub fn column_groups(
+        mut self,
+        groups: impl Into<Vec<(String, RangeInclusive<usize>)>>,
+    ) -> Self {
+        self.column_groups = groups.into();
+        self
+    }
+
+    /// Sets the number of leading columns of the [`Table`] kept in view while
+    /// scrolling horizontally. Defaults to `0`.
+    pub fn frozen_columns(mut self, columns: usize) -> Self {
+        self.frozen_columns = columns;
+        self
+    }
+
+    /// Sets the maximum width a column of the [`Table`] grows to when sized to
+    /// its contents.
+    pub fn max_column_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.max_column_width = width.into().0.max(0.0);
+        self
+    }
+
+    /// Sets whether the columns of the [`Table`] share out the width left
+    /// over by their contents, so the cells span the whole [`Table`].
+    /// Defaults to `false`.
+    ///
+    /// Resized columns and those given a width keep it. Best paired with a
+    /// [`Length::Fill`] width.
+    pub fn fill_columns(mut self, fill: bool) -> Self {
+        self.fill_columns = fill;
+        self
+    }
+
+    /// Sets the width of the grid lines between the cells of the [`Table`],
+    /// with `0.0` hiding them. Defaults to `1.0`.
+    ///
+    /// The lines are drawn within the gaps between cells, so they never
+    /// grow past the [`Table::cell_gap`], and the resize handles keep their
+    /// size.
+    pub fn grid_line_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.grid_line_width = width.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the spacing between the cells of the [`Table`]. Defaults to
+    /// [`CELL_GAP`].
+    pub fn cell_gap(mut self, gap: f32) -> Self {
+        self.cell_gap = gap.max(0.0);
+        self
+    }
+
+    /// Sets the maximum height of the text of a [`Table`] cell. Defaults to
+    /// [`MAX_CELL_HEIGHT`].
+    pub fn max_cell_height(mut self, height: f32) -> Self {
+        self.max_cell_height = height.max(0.0);
+        self
+    }
+
+    /// Sets the maximum number of page buttons shown by the pagination of
+    /// the [`Table`], ellipses included. Defaults to [`PAGINATION_LIMIT`],
+    /// with a minimum of `5`.
+    pub fn pagination_limit(mut self, limit: usize) -> Self {
+        self.pagination_limit = limit.max(5);
+        self
+    }
+
+    /// Sets where clicking an ellipsis of the pagination moves to. Defaults
+    /// to [`EllipsisJump::Midpoint`].
+    pub fn ellipsis_jump(mut self, jump: EllipsisJump) -> Self {
+        self.ellipsis_jump = jump;
+        self
+    }
+
+    /// Sets how the text of the [`Table`] cells overflowing their columns is
+    /// shown. Defaults to [`Overflow::Clip`].
+    ///
+    /// Columns are as wide as their contents unless limited by
+    /// [`Table::max_column_width`], [`Table::column_widths`] or resizing.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Wraps the text of the [`Table`] cells at the width of their columns,
+    /// growing the rows to fit up to `max_lines` lines.
+    ///
+    /// A shorthand for [`Overflow::Grow`].
+    pub fn wrap(mut self, max_lines: usize) -> Self {
+        self.overflow = Overflow::Grow;
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Sets the width of the scrollbars of the [`Table`] cells.
+    ///
+    /// A width of `0` hides the scrollbars.
+    pub fn scrollbar_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.scrollbar_width = width.into().0.max(0.0);
+        self
+    }
+
+    /// Sets the multiplier of the wheel scrolling of the [`Table`] cells.
+    /// Defaults to `5.0`.
+    pub fn scroll_step(mut self, step: f32) -> Self {
+        self.scroll_step = step.max(0.0);
+        self
+    }
+
+    /// Eases the wheel scrolling of the [`Table`] cells over `duration_ms`
+    /// instead of jumping to the new offset.
+    ///
+    /// Dragging the scrollbars, resizing and the other ways of scrolling still
+    /// move the cells immediately.
+    pub fn smooth_scrolling(mut self, duration_ms: f32, easing: Easing) -> Self {
+        self.smooth_scrolling = Some((duration_ms, easing));
+        self
+    }
+
+    /// Sets how much Alt and the arrow keys resize the selected columns or
+    /// rows of the [`Table`] by. Defaults to `8.0`.
+    pub fn resize_step(mut self, step: impl Into<Pixels>) -> Self {
+        self.resize_step = step.into().0.max(0.0);
+        self
+    }
+
+    /// Sets whether the row numbering column of the [`Table`] is shown.
+    /// Defaults to `true`.
+    ///
+    /// Without it, rows cannot be selected or moved as a whole.
+    pub fn numbering(mut self, numbering: bool) -> Self {
+        self.numbering = numbering;
+        self
+    }
+
+    /// Sets whether the kind of each column is shown under its header.
+    /// Defaults to `true`.
+    pub fn show_column_kinds(mut self, show: bool) -> Self {
+        self.show_column_kinds = show;
+        self
+    }
+
+    /// Sets whether the [`Table`] is read-only. Defaults to `false`.
+    ///
+    /// A read-only [`Table`] cannot be edited, pasted into, cleared or
+    /// rearranged. Selecting, copying, paging and resizing still work.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets whether the data of the [`Table`] is still loading. Defaults to
+    /// `false`.
+    ///
+    /// A loading [`Table`] keeps its headers and pagination in view, but dims
+    /// its cells under a "Loading…" label and ignores all input. Any edit in
+    /// progress is discarded.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the predicate deciding which columns of the [`Table`] can have
+    /// their cells edited. Cells of other columns can only be selected.
+    pub fn editable_columns(mut self, predicate: impl Fn(usize) -> bool + 'a) -> Self {
+        self.editable_columns = Some(Box::new(predicate));
+        self
+    }
+
+    /// Sets the predicate deciding which columns of the [`Table`] are hidden.
+    ///
+    /// Hidden columns take no room and cannot be edited, while the other
+    /// columns keep their indices in every [`Action`].
+    pub fn hidden_columns(mut self, predicate: impl Fn(usize) -> bool + 'a) -> Self {
+        self.hidden_columns = Some(Box::new(predicate));
+        self
+    }
+
+    /// Sets whether the headers of the [`Table`] can be edited. Defaults to
+    /// `true`.
+    pub fn editable_headers(mut self, editable: bool) -> Self {
+        self.editable_headers = editable;
+        self
+    }
+
+    /// Sets whether boolean columns of the [`Table`] are drawn as checkmarks
+    /// which are toggled by a click or Space. Defaults to `true`.
+    ///
+    /// When disabled, boolean cells are shown and edited as text.
+    pub fn bool_as_checkbox(mut self, checkbox: bool) -> Self {
+        self.bool_as_checkbox = checkbox;
+        self
+    }
+
+    /// Sets whether the row under the cursor is highlighted. Defaults to
+    /// `true`.
+    pub fn highlight_hovered_row(mut self, highlight: bool) -> Self {
+        self.highlight_hovered_row = highlight;
+        self
+    }
+
+    /// Sets whether a search input is shown next to the status. Defaults to
+    /// `false`.
+    ///
+    /// Each change of its query publishes an [`Action::Search`]. The page
+    /// shown before searching is restored once the query is cleared.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Sets whether the headers show a sort arrow. Defaults to `false`.
+    ///
+    /// Clicking an arrow, or Alt+clicking a header label, publishes an
+    /// [`Action::Sort`] cycling the column through ascending, descending and
+    /// unsorted. The [`Table`] never reorders the data itself.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets the column the data is sorted by and its [`SortOrder`], shown by
+    /// the header sort arrows.
+    pub fn sorted_by(mut self, sorted_by: Option<(usize, SortOrder)>) -> Self {
+        self.sorted_by = sorted_by;
         self
     }
 
@@ -130,6 +590,23 @@ where
         self
     }
 
+    /// Sets the [`Shaping`] of all the text in the [`Table`]. Defaults to
+    /// [`Shaping::Advanced`].
+    ///
+    /// [`Shaping::Basic`] lays out large pages noticeably faster, but breaks
+    /// complex scripts, ligatures and emoji.
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the [`LineHeight`] of all the text in the [`Table`], which the
+    /// row heights follow. Defaults to [`LineHeight::default`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
     /// Sets the [`Font`] used for headers in the [`Table`].
     pub fn header_font(mut self, font: Renderer::Font) -> Self {
         self.header_font = Some(font);
@@ -142,6 +619,20 @@ where
         self
     }
 
+    /// Sets the text size of the headers in the [`Table`], defaulting to
+    /// the [`Table::text_size`].
+    pub fn header_text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.header_text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text size of the row numbering in the [`Table`], defaulting
+    /// to the [`Table::text_size`].
+    pub fn numbering_text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.numbering_text_size = Some(size.into());
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Table`].
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
         self.padding = padding.into();
@@ -160,6 +651,51 @@ where
         self
     }
 
+    /// Sets the text shown at the left end of the status area.
+    ///
+    /// Segments which do not fit are truncated.
+    pub fn status_left(mut self, status: impl Into<String>) -> Self {
+        self.status_left = Some(status.into());
+        self
+    }
+
+    /// Sets the text shown at the right end of the status area.
+    ///
+    /// Segments which do not fit are truncated.
+    pub fn status_right(mut self, status: impl Into<String>) -> Self {
+        self.status_right = Some(status.into());
+        self
+    }
+
+    /// Sets whether ArrowUp and ArrowDown commit a cell edit and move the
+    /// selection up or down the current column.
+    ///
+    /// When disabled, or when Ctrl is held, the arrows move the text cursor to
+    /// the start or end of the value.
+    pub fn arrow_keys_commit(mut self, commit: bool) -> Self {
+        self.arrow_keys_commit = commit;
+        self
+    }
+
+    /// Sets whether double clicking a cell or header starts editing it.
+    /// Defaults to `true`.
+    ///
+    /// An [`Action::DoubleClick`] is published either way, so applications
+    /// can open their own views instead.
+    pub fn double_click_edits(mut self, edits: bool) -> Self {
+        self.double_click_edits = edits;
+        self
+    }
+
+    /// Sets whether the text cursor of the [`Table`] inputs blinks. Defaults
+    /// to `true`.
+    ///
+    /// A steady cursor never needs redrawing on its own.
+    pub fn cursor_blink(mut self, blink: bool) -> Self {
+        self.cursor_blink = blink;
+        self
+    }
+
     /// Sets the message that should be produced when some action is performed in
     /// the [`Table`].
     ///
@@ -175,6 +711,113 @@ where
         self
     }
 
+    /// Sets the message produced when the page of the [`Table`] changes,
+    /// given the previous and current zero-based pages.
+    ///
+    /// Unlike [`Action::PageChange`], whose pages start from `1`, both pages
+    /// here start from `0`.
+    pub fn on_page_change(mut self, on_page_change: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_page_change = Some(Box::new(on_page_change));
+        self
+    }
+
+    /// Sets the closure producing the tooltip of a data cell, given its
+    /// absolute row and column.
+    ///
+    /// The tooltip is shown when the cursor rests over the cell.
+    pub fn on_tooltip(mut self, callback: impl Fn(usize, usize) -> Option<String> + 'a) -> Self {
+        self.on_tooltip = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the closure producing the validation error of a data cell, given
+    /// its absolute row and column.
+    ///
+    /// Cells with an error are styled apart, and the error of the selected
+    /// cell is shown in the status area.
+    pub fn cell_errors(mut self, errors: impl Fn(usize, usize) -> Option<String> + 'a) -> Self {
+        self.cell_errors = Some(Box::new(errors));
+        self
+    }
+
+    /// Sets the closure formatting the value of a data cell for display, given
+    /// the value and its absolute row and column.
+    ///
+    /// Editing a cell still starts from its unformatted value.
+    pub fn format_cell(mut self, format: impl Fn(&str, usize, usize) -> String + 'a) -> Self {
+        self.format_cell = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the closure producing the [`CellStyle`] of a data cell, given its
+    /// absolute row, column and value.
+    ///
+    /// A returned [`CellStyle`] replaces the alternating row colors of the
+    /// cell. Selections are still drawn on top.
+    pub fn cell_style(
+        mut self,
+        style: impl Fn(usize, usize, &str) -> Option<CellStyle> + 'a,
+    ) -> Self {
+        self.cell_style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the closure producing the footer of a column, such as an
+    /// aggregate of its values.
+    ///
+    /// The footer row is pinned below the cells and scrolls horizontally
+    /// with them.
+    pub fn footer(mut self, footer: impl Fn(usize) -> Option<String> + 'a) -> Self {
+        self.footer = Some(Box::new(footer));
+        self
+    }
+
+    /// Sets the closure overriding the horizontal alignment of the cells of a
+    /// column, falling back to [`RawTable::kind_alignment`] when `None`.
+    ///
+    /// Headers stay centered.
+    pub fn column_alignment(
+        mut self,
+        alignment: impl Fn(usize) -> Option<Horizontal> + 'a,
+    ) -> Self {
+        self.column_alignment = Some(Box::new(alignment));
+        self
+    }
+
+    /// Sets the text shown in place of cells without a value. Defaults to an
+    /// empty string.
+    ///
+    /// Editing such a cell starts from an empty value.
+    pub fn none_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.none_placeholder = placeholder.into();
+        self
+    }
+
+    /// Highlights the data cells whose displayed value contains `query`,
+    /// ignoring case. An empty `query` highlights nothing.
+    ///
+    /// F3 selects the next matching cell and Shift+F3 the previous one,
+    /// moving to its page.
+    pub fn highlight_matches(mut self, query: &str) -> Self {
+        self.match_query = query.to_lowercase();
+        self
+    }
+
+    /// Sets the [`NumericFormat`] of the numeric columns of the [`Table`].
+    ///
+    /// Thousands separators are also accepted while editing, and are removed
+    /// from submitted values.
+    pub fn numeric_format(mut self, format: NumericFormat) -> Self {
+        self.numeric_format = format;
+        self
+    }
+
+    /// Sets the [`widget::Id`] of the [`Table`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the style class of the [`Table`].
     pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
         self.class = class.into();
@@ -182,7 +825,7 @@ where
     }
 
     /// Sets the style of the [`Table`].
-    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
     where
         Theme::Class<'a>: From<StyleFn<'a, Theme>>,
     {
@@ -196,12 +839,200 @@ where
         if self.page_limit == 0 {
             return 0;
         }
-        self.raw.height() / self.page_limit
+        self.raw.height().saturating_sub(1) / self.page_limit
     }
 
     fn multiple_pages(&self) -> bool {
         self.raw.height() > self.page_limit
     }
+
+    /// Returns the text bounds of a cell in a column `width` wide, if the
+    /// cells wrap.
+    fn wrap_bounds(&self, width: f32, size: Pixels) -> Option<Size> {
+        if self.overflow != Overflow::Grow {
+            return None;
+        }
+
+        let lines = self.max_lines;
+        let width = (width - self.cell_padding.horizontal()).max(0.0);
+        let line_height = self.line_height.to_absolute(size).0;
+
+        Some(Size::new(width, line_height * lines as f32))
+    }
+
+    /// Returns `true` if the header or cells of `column` can be edited as text.
+    fn is_editable(&self, is_header: bool, column: usize) -> bool {
+        self.is_unlocked(is_header, column) && (is_header || !self.is_checkbox(column))
+    }
+
+    /// Returns `true` if the cells of `column` are checkmarks which can be
+    /// toggled.
+    fn is_toggleable(&self, column: usize) -> bool {
+        self.is_checkbox(column) && self.is_unlocked(false, column)
+    }
+
+    /// Returns `true` if the header or cells of `column` can be changed.
+    fn is_unlocked(&self, is_header: bool, column: usize) -> bool {
+        if self.read_only || self.is_hidden(column) {
+            return false;
+        }
+
+        if is_header {
+            self.editable_headers
+        } else {
+            self.editable_columns
+                .as_ref()
+                .is_none_or(|predicate| predicate(column))
+        }
+    }
+
+    /// Returns `true` if `column` is hidden.
+    fn is_hidden(&self, column: usize) -> bool {
+        self.hidden_columns
+            .as_ref()
+            .is_some_and(|predicate| predicate(column))
+    }
+
+    /// Applies `step` to `selection` until its active cell is in a shown
+    /// column, leaving it in place if none is reached.
+    fn step_shown(&self, selection: &mut Selection, mut step: impl FnMut(&mut Selection)) {
+        let previous = selection.clone();
+
+        for _ in 0..self.cols.max(1) {
+            step(selection);
+
+            if !self.is_hidden(selection.active().1) {
+                return;
+            }
+        }
+
+        *selection = previous;
+    }
+
+    /// Returns the horizontal alignment of the cells of `column`.
+    fn cell_alignment(&self, column: usize, kind: &Raw::ColumnKind) -> Horizontal {
+        self.column_alignment
+            .as_ref()
+            .and_then(|alignment| alignment(column))
+            .unwrap_or_else(|| self.raw.kind_alignment(kind))
+    }
+
+    /// Returns `true` if the cells of `column` are drawn as checkmarks.
+    fn is_checkbox(&self, column: usize) -> bool {
+        self.bool_as_checkbox
+            && self
+                .raw
+                .column_kind(column)
+                .is_some_and(|kind| self.raw.is_boolean(&kind))
+    }
+
+    /// Returns `true` if the cell at `row` and `column` holds a true value.
+    fn is_checked(&self, row: usize, column: usize) -> bool {
+        self.raw
+            .cell(row, column)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+    }
+
+    /// Returns the displayed text of the cell at `row` and `column`.
+    fn display_cell(&self, row: usize, column: usize) -> Cow<'_, str> {
+        self.formatted_cell(row, column)
+            .unwrap_or(Cow::Borrowed(&self.none_placeholder))
+    }
+
+    /// Returns the formatted value of the cell at `row` and `column`, if it
+    /// has one.
+    fn formatted_cell(&self, row: usize, column: usize) -> Option<Cow<'_, str>> {
+        let value = self.raw.cell(row, column)?;
+
+        if self.is_checkbox(column) {
+            let mark = if self.is_checked(row, column) {
+                CHECKED_MARK
+            } else {
+                UNCHECKED_MARK
+            };
+
+            return Some(Cow::Borrowed(mark));
+        }
+
+        match self.format_cell.as_ref() {
+            Some(format) => Some(Cow::Owned(format(&value, row, column))),
+            None if self.is_numeric(column) => Some(Cow::Owned(self.numeric_format.format(&value))),
+            None => Some(value),
+        }
+    }
+
+    /// Returns `true` if the displayed `value` of a cell contains the
+    /// highlighted query.
+    fn is_match(&self, value: &str) -> bool {
+        !self.match_query.is_empty() && value.to_lowercase().contains(&self.match_query)
+    }
+
+    /// Returns `true` if the cells of `column` hold numbers.
+    fn is_numeric(&self, column: usize) -> bool {
+        self.raw
+            .column_kind(column)
+            .is_some_and(|kind| self.raw.is_numeric(&kind))
+    }
+
+    /// Returns `true` if `character` can be typed into the cells of `column`.
+    fn accepts(&self, kind: &Raw::ColumnKind, column: usize, character: char) -> bool {
+        self.raw.column_filter(kind, character)
+            || (self.numeric_format.thousands_separator == Some(character)
+                && self.is_numeric(column))
+    }
+
+    /// Returns `true` if the edited `value` of a cell in `column` is valid,
+    /// ignoring thousands separators in numeric columns.
+    fn validates(&self, kind: &Raw::ColumnKind, column: usize, value: &str) -> bool {
+        match self.numeric_format.thousands_separator {
+            Some(separator) if self.is_numeric(column) => self
+                .raw
+                .column_validate(kind, &value.replace(separator, "")),
+            _ => self.raw.column_validate(kind, value),
+        }
+    }
+
+    /// Returns the validation error of the edited `value` of a cell in
+    /// `column`, if any.
+    fn edit_error(&self, value: &str, column: usize) -> Option<String> {
+        let kind = self.raw.column_kind(column)?;
+
+        self.raw
+            .validate(&kind, &self.submitted(value.to_owned(), column))
+            .err()
+    }
+
+    /// Returns the edited `value` of a cell in `column` as it is submitted.
+    fn submitted(&self, value: String, column: usize) -> String {
+        if self.is_numeric(column) {
+            self.numeric_format.strip(value)
+        } else {
+            value
+        }
+    }
+
+    /// Returns the [`CellStyle`] of the cell at `row` and `column`.
+    fn styled_cell(&self, row: usize, column: usize) -> Option<CellStyle> {
+        let style = self.cell_style.as_ref()?;
+        let value = self.raw.cell(row, column).unwrap_or_default();
+
+        style(row, column, &value)
+    }
+
+    /// Returns the validation error of the cell at `row` and `column`.
+    fn cell_error(&self, row: usize, column: usize) -> Option<String> {
+        self.cell_errors
+            .as_ref()
+            .and_then(|errors| errors(row, column))
+    }
+
+    fn has_actions(&self) -> bool {
+        self.multiple_pages() || self.shows_page_sizes()
+    }
+
+    fn shows_page_sizes(&self) -> bool {
+        self.paginate && !self.page_sizes.is_empty()
+    }
 }
 
 impl<Raw, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -241,7 +1072,14 @@ where
     ) {
         let state = tree.state.downcast_ref::<State<Renderer>>();
         let bounds = layout.bounds();
-        let style = theme.style(&self.class);
+        let status = if self.on_action.is_none() {
+            Status::Disabled
+        } else if state.is_focused() {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+        let style = theme.style(&self.class, status);
 
         let Some(clipped_viewport) = bounds.intersection(viewport) else {
             return;
@@ -287,6 +1125,25 @@ where
         state.mouse_interaction(self, layout, cursor)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        let mut request: Option<Request> = None;
+        operation.custom(&mut request, self.id.as_ref());
+
+        if let Some(request) = request {
+            state.on_request(self, layout, request);
+        }
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -294,16 +1151,21 @@ where
         layout: layout::Layout<'_>,
         cursor: advanced::mouse::Cursor,
         renderer: &Renderer,
-        _clipboard: &mut dyn advanced::Clipboard,
+        clipboard: &mut dyn advanced::Clipboard,
         shell: &mut advanced::Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         if self.on_action.is_none() {
             return event::Status::Ignored;
         }
 
         let state = state.state.downcast_mut::<State<Renderer>>();
-        state.on_update(self, renderer, event, layout, cursor, shell)
+        let status = state.on_update(
+            self, renderer, event, layout, cursor, clipboard, shell, viewport,
+        );
+        state.revalidate(self, shell);
+
+        status
     }
 
     fn overlay<'b>(
@@ -338,16 +1200,18 @@ fn text<Renderer: text::Renderer>(
     font: Renderer::Font,
     horizontal: Horizontal,
     size: Pixels,
+    shaping: Shaping,
+    line_height: LineHeight,
 ) -> text::Text<&str, Renderer::Font> {
     text::Text {
         content,
         bounds,
         size,
-        line_height: LineHeight::default(),
+        line_height,
         horizontal_alignment: horizontal,
         vertical_alignment: Vertical::Center,
         font,
-        shaping: Shaping::Advanced,
+        shaping,
         wrapping: Wrapping::Word,
     }
 }
@@ -379,14 +1243,19 @@ fn draw<Renderer>(
     renderer.fill_paragraph(paragraph, Point::new(x, y), text_color, *viewport);
 }
 
-fn gen_pagination(start: isize, end: isize, curr: isize) -> Vec<String> {
-    let extra_left = (4 - (curr - start - 1)).max(0);
-    let extra_right = (4 - (end - 1 - curr)).max(0);
+fn gen_pagination(start: isize, end: isize, curr: isize, limit: usize) -> Vec<String> {
+    // Both ends and their ellipses take up four of the `limit` pages
+    let around = limit as isize - 5;
+    let left = around / 2;
+    let right = around - left;
 
-    let curr_end = (curr + 3 + extra_left).min(end - 1);
-    let curr_start = (curr - 3 - extra_right).max(start + 1);
+    let extra_left = (left + 1 - (curr - start - 1)).max(0);
+    let extra_right = (right + 1 - (end - 1 - curr)).max(0);
 
-    let mut output = Vec::with_capacity(11);
+    let curr_end = (curr + right + extra_left).min(end - 1);
+    let curr_start = (curr - left - extra_right).max(start + 1);
+
+    let mut output = Vec::with_capacity(limit);
     output.push(start.to_string());
 
     if curr_start != start + 1 {
@@ -422,18 +1291,56 @@ fn alignment_offset(
     }
 }
 
+/// Returns the visual line of `paragraph` holding the character `index` of
+/// `value`, the index its line starts at and the height of a line.
+fn cursor_line(paragraph: &impl text::Paragraph, value: &str, index: usize) -> (usize, usize, f32) {
+    let lines = (0..)
+        .take_while(|line| paragraph.grapheme_position(*line, 0).is_some())
+        .count()
+        .max(1);
+    let line_height = paragraph.min_bounds().height / lines as f32;
+
+    let mut line = 0;
+    let mut start = 0;
+
+    for next in 1..lines {
+        let first = paragraph
+            .hit_test(Point::new(0.0, (next as f32 + 0.5) * line_height))
+            .map(|hit| char_index(value, hit.cursor()));
+
+        match first {
+            Some(first) if first <= index => {
+                line = next;
+                start = first;
+            }
+            _ => break,
+        }
+    }
+
+    (line, start, line_height)
+}
+
+/// Returns the caret bounds of `cursor_index`, relative to the top left of the
+/// text, and the horizontal scroll offset keeping it visible.
 fn measure_cursor_and_scroll_offset(
     paragraph: &impl text::Paragraph,
+    value: &str,
     text_bounds: Rectangle,
     cursor_index: usize,
-) -> (f32, f32) {
+) -> (Rectangle, f32) {
+    let (line, start, line_height) = cursor_line(paragraph, value, cursor_index);
+
     let grapheme_position = paragraph
-        .grapheme_position(0, cursor_index)
+        .grapheme_position(line, cursor_index - start)
         .unwrap_or(Point::ORIGIN);
 
     let offset = ((grapheme_position.x + 5.0) - text_bounds.width).max(0.0);
+    let caret = Rectangle::new(
+        Point::new(grapheme_position.x, line as f32 * line_height),
+        Size::new(0.0, line_height),
+    );
 
-    (grapheme_position.x, offset)
+    (caret, offset)
 }
 
 fn offset<Renderer: text::Renderer>(
@@ -450,7 +1357,8 @@ fn offset<Renderer: text::Renderer>(
             utils::State::Selection { end, .. } => end,
         };
 
-        let (_, offset) = measure_cursor_and_scroll_offset(cell.raw(), text_bounds, focus_position);
+        let (_, offset) =
+            measure_cursor_and_scroll_offset(cell.raw(), value, text_bounds, focus_position);
 
         offset
     } else {
@@ -458,52 +1366,105 @@ fn offset<Renderer: text::Renderer>(
     }
 }
 
+/// Finds the cursor index at `x` and `y`, relative to the top left of
+/// `text_bounds`.
 fn find_cursor_position<Renderer: text::Renderer>(
     text_bounds: Rectangle,
     value: &str,
     state: &State<Renderer>,
     cell: &Cell<Renderer>,
     x: f32,
+    y: f32,
 ) -> Option<usize> {
     let offset = offset::<Renderer>(text_bounds, value, state, cell);
-    let value = value.to_string();
 
-    let char_offset = cell
+    // The text is vertically centered in its bounds
+    let text_height = cell.min_bounds().height;
+    let text_top = (text_bounds.height - text_height).max(0.0) / 2.0;
+    let y = (y - text_top).clamp(0.0, (text_height - 1.0).max(0.0));
+
+    let byte_offset = cell
         .raw()
-        .hit_test(Point::new(x + offset, text_bounds.height / 2.0))
+        .hit_test(Point::new(x + offset, y))
         .map(text::Hit::cursor)?;
 
-    let res = value[..char_offset.min(value.len())].len();
+    Some(char_index(value, byte_offset))
+}
 
-    Some(res)
+/// Returns the index of the character at `byte_offset` in `value`, as
+/// counted by [`utils::Cursor`].
+fn char_index(value: &str, byte_offset: usize) -> usize {
+    value
+        .char_indices()
+        .take_while(|(offset, _)| *offset < byte_offset)
+        .count()
 }
 
+/// Returns the start and end character indices of the word around `index` in
+/// `text`. Both are `index` outside of a word.
 fn word_boundary(text: &str, index: usize) -> (usize, usize) {
-    if index >= text.len() {
-        return (text.len(), text.len());
-    }
-
     let chars = text.chars().collect::<Vec<char>>();
     let len = chars.len();
 
-    if !chars[index].is_alphanumeric() && chars[index] != '_' {
+    if index >= len {
+        return (len, len);
+    }
+
+    if !utils::is_word(chars[index]) {
         return (index, index);
     }
 
     let mut start = index;
     let mut end = index;
 
-    while start > 0 && (chars[start - 1].is_alphanumeric() || chars[start - 1] == '_') {
+    while start > 0 && utils::is_word(chars[start - 1]) {
         start -= 1;
     }
 
-    while end < len && (chars[end].is_alphanumeric() || chars[end] == '_') {
+    while end < len && utils::is_word(chars[end]) {
         end += 1;
     }
 
-    if end + 1 < len {
-        end += 1;
+    (start, end)
+}
+
+/// Swaps the values of two keys in `map`, moving a value if the other key is
+/// absent.
+fn swap_entries<V>(map: &mut HashMap<usize, V>, a: usize, b: usize) {
+    let first = map.remove(&a);
+    let second = map.remove(&b);
+
+    if let Some(value) = first {
+        map.insert(b, value);
     }
 
-    (start, end)
+    if let Some(value) = second {
+        map.insert(a, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUE: &str = "foo_bar, baz.qux";
+
+    #[test]
+    fn word_boundary_around_words() {
+        assert_eq!(word_boundary(VALUE, 0), (0, 7));
+        assert_eq!(word_boundary(VALUE, 3), (0, 7));
+        assert_eq!(word_boundary(VALUE, 4), (0, 7));
+        assert_eq!(word_boundary(VALUE, 9), (9, 12));
+        assert_eq!(word_boundary(VALUE, 15), (13, 16));
+    }
+
+    #[test]
+    fn word_boundary_outside_words() {
+        assert_eq!(word_boundary(VALUE, 7), (7, 7));
+        assert_eq!(word_boundary(VALUE, 8), (8, 8));
+        assert_eq!(word_boundary(VALUE, 12), (12, 12));
+        assert_eq!(word_boundary(VALUE, 16), (16, 16));
+        assert_eq!(word_boundary(VALUE, 99), (16, 16));
+        assert_eq!(word_boundary("", 0), (0, 0));
+    }
 }
@@ -0,0 +1,90 @@
+use iced::{
+    advanced::widget::{self, Id, Operation},
+    Rectangle, Task,
+};
+use std::any::Any;
+
+/// A change requested of a [`Table`] through a widget operation.
+///
+/// [`Table`]: super::Table
+#[derive(Debug, Clone, Copy)]
+pub enum Request {
+    /// Shows the data cell at `row` and `column`, selecting it if `select`.
+    Reveal {
+        row: usize,
+        column: usize,
+        select: bool,
+    },
+    /// Moves to the page, clamped to the last page.
+    GoToPage(usize),
+}
+
+/// Produces a [`Task`] which moves the [`Table`] with the given [`Id`] to the
+/// page of the data cell at the absolute `row` and `column`, scrolling it
+/// into view.
+///
+/// The cell is also selected if `select`. No [`Action`] is published for the
+/// resulting page change or selection.
+///
+/// [`Table`]: super::Table
+/// [`Action`]: super::Action
+pub fn reveal_cell<Message: Send + 'static>(
+    id: impl Into<Id>,
+    row: usize,
+    column: usize,
+    select: bool,
+) -> Task<Message> {
+    let request = Request::Reveal {
+        row,
+        column,
+        select,
+    };
+
+    widget::operate(Requester {
+        id: id.into(),
+        request,
+    })
+}
+
+/// Produces a [`Task`] which moves the [`Table`] with the given [`Id`] to
+/// the zero-based `page`, clamped to the last page.
+///
+/// No [`Action`] is published for the resulting page change.
+///
+/// [`Table`]: super::Table
+/// [`Action`]: super::Action
+pub fn go_to_page<Message: Send + 'static>(id: impl Into<Id>, page: usize) -> Task<Message> {
+    widget::operate(Requester {
+        id: id.into(),
+        request: Request::GoToPage(page),
+    })
+}
+
+/// Hands a [`Request`] to the [`Table`] with the given [`Id`].
+///
+/// [`Table`]: super::Table
+struct Requester {
+    id: Id,
+    request: Request,
+}
+
+impl<T> Operation<T> for Requester {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+
+        if let Some(request) = state.downcast_mut::<Option<Request>>() {
+            *request = Some(self.request);
+        }
+    }
+}
@@ -4,23 +4,31 @@ use iced::{
         layout::{self, Limits, Node},
         mouse::{self, click},
         renderer::Quad,
-        text::{self, Paragraph},
+        text::{self, LineHeight, Paragraph, Shaping},
+        widget::operation::Focusable,
         Shell,
     },
     alignment::Horizontal,
     event, keyboard,
     time::{Duration, Instant},
-    touch, window, Background, Color, Event, Padding, Pixels, Point, Rectangle, Size, Vector,
+    touch, window, Background, Border, Color, Event, Padding, Pixels, Point, Rectangle, Size,
+    Vector,
 };
 
-use super::style::{Catalog, Style};
+use super::style::{Catalog, CellStyle, Status, Style};
 use super::utils::{
-    self, Action, Editing, Editor, Focus, KeyPress, Motion, RawTable, Resizing, Selection,
+    self, Action, Axis, CellTarget, EditState, Editing, Editor, EllipsisJump, Focus, History,
+    KeyPress, Motion, Overflow, RawTable, ResizeDirection, Resizing, Scrollbar, Selection, Shaped,
+    SmoothScroll, SortOrder, TargetKind, TooltipTarget, TouchPan,
 };
 use super::{
     alignment_offset, draw, find_cursor_position, gen_pagination, measure_cursor_and_scroll_offset,
-    word_boundary, Cell, Table, PAGINATION_ELLIPSIS,
+    swap_entries, word_boundary, Cell, Request, Table, CELL_GAP, MAX_CELL_HEIGHT,
+    PAGINATION_ELLIPSIS,
 };
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::ops::{Range, RangeInclusive};
 
 mod overlay;
 pub use overlay::Overlay;
@@ -28,22 +36,53 @@ pub use overlay::Overlay;
 const BACK: &str = "‹ Back";
 const NEXT: &str = "Next ›";
 const GOTO_PAGE: &str = "Page:";
+const PAGE_SIZES: &str = "Rows:";
 const GOTO_GO: &str = "Go";
+const SEARCH: &str = "Search…";
+const LOADING: &str = "Loading…";
+const OVERFLOW_ELLIPSIS: &str = "…";
+/// The header sort arrows when unsorted, ascending and descending.
+const SORT_ARROWS: [&str; 3] = ["↕", "▲", "▼"];
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
-/// Spacing between cells
-const CELL_GAP: f32 = 3.5;
+/// How long a notice replaces the default status
+const NOTICE_DURATION: Duration = Duration::from_secs(2);
+/// How long the cursor rests over a cell before its tooltip shows
+const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
 
 pub struct State<Renderer: text::Renderer> {
     cells: Vec<Cell<Renderer>>,
     numbering: Vec<Cell<Renderer>>,
     headers: Vec<(Cell<Renderer>, Cell<Renderer>)>,
+    groups: Vec<Cell<Renderer>>,
+    /// The height of the column groups band along with its gap, if any.
+    groups_height: f32,
+    /// The paragraphs of [`SORT_ARROWS`].
+    sort_arrows: [Cell<Renderer>; 3],
+    /// The width reserved right of the header labels for the sort arrows.
+    sort_width: f32,
     paginations: Vec<(Cell<Renderer>, String)>,
+    /// Whether the pagination only shows the current page, as on narrow tables.
+    compact_pagination: bool,
     page_next: Cell<Renderer>,
     page_back: Cell<Renderer>,
     goto_input: (Cell<Renderer>, String),
+    search_input: (Cell<Renderer>, String),
+    search_placeholder: Cell<Renderer>,
+    /// The page shown before the current search started.
+    search_page: Option<usize>,
+    /// Whether a [`Request`] changed the state after the last layout.
+    is_stale: bool,
+    /// The header or data cell edited as of the last event.
+    edited: Option<CellTarget>,
+    /// The last published [`EditState`].
+    edit_state: Option<EditState>,
     goto_page: Cell<Renderer>,
     goto_go: Cell<Renderer>,
+    page_sizes_label: Cell<Renderer>,
+    page_sizes: Vec<Cell<Renderer>>,
     status: (Cell<Renderer>, String),
+    status_segments: (Cell<Renderer>, Cell<Renderer>),
+    notice: Option<(String, Instant)>,
     pages_padding: Padding,
     page: usize,
     page_size: Pixels,
@@ -56,37 +95,124 @@ pub struct State<Renderer: text::Renderer> {
     editing: Option<Editing>,
     scroll_offset: Vector,
     cells_dim: Size,
+    /// The combined width of the frozen columns, gaps included.
+    frozen_width: f32,
+    /// The validation error of the cell being edited.
+    edit_error: Option<String>,
+    /// The [`Shaping`] of every paragraph, taken from the [`Table`].
+    shaping: Shaping,
+    /// The [`LineHeight`] of every paragraph, taken from the [`Table`].
+    line_height: LineHeight,
+    /// The spacing between cells, taken from the [`Table`].
+    cell_gap: f32,
+    cursor_blink: bool,
+    /// The bounds of every paragraph, taken from the [`Table`].
+    max_cell: Size,
+    footer: Vec<Cell<Renderer>>,
+    /// The height of the footer row pinned below the cells.
+    footer_height: f32,
     min_widths: Vec<f32>,
+    /// The number of hidden columns the status was last written with.
+    hidden: usize,
     min_heights: Vec<f32>,
+    /// The widths of manually resized columns, keyed by layout column.
+    manual_widths: HashMap<usize, f32>,
+    /// The left over width handed to each column at the last layout, keyed
+    /// by layout column.
+    filled_widths: HashMap<usize, f32>,
+    /// The heights of manually resized rows, keyed by absolute data row
+    /// counted from `1`, with the headers row at `0`.
+    manual_heights: HashMap<usize, f32>,
+    /// The cells truncated with an ellipsis at the last layout.
+    truncated: HashSet<usize>,
+    /// The text last shaped into each cell, keyed by cell index.
+    shaped: HashMap<usize, Shaped>,
+    /// The font and text size the [`State::shaped`] cells were shaped with.
+    shaped_with: Option<(Renderer::Font, Pixels)>,
+    /// The paragraph of [`OVERFLOW_ELLIPSIS`] ending truncated cells.
+    cell_ellipsis: Cell<Renderer>,
+    /// The paragraph of [`OVERFLOW_ELLIPSIS`] ending truncated status segments.
+    status_ellipsis: Cell<Renderer>,
+    /// The cells showing the placeholder of missing values at the last layout.
+    placeholders: HashSet<usize>,
+    /// The page cells matching the highlighted query.
+    matches: HashSet<usize>,
+    /// The truncated cell under the cursor and its full text.
+    overflow_hover: Option<(usize, Cell<Renderer>)>,
+    /// What is under the resting cursor, and since when.
+    tooltip_hover: Option<(TooltipTarget, Instant)>,
+    /// The `(row, column)` of the data cell under the cursor.
+    hovered_cell: Option<(usize, usize)>,
+    /// The shown tooltip and the cursor position it was requested at.
+    tooltip: Option<(Point, Cell<Renderer>)>,
+    /// The paragraph of [`LOADING`].
+    loading: Cell<Renderer>,
     resizing: Option<Resizing>,
     selection: Option<Selection>,
     page_limit: usize,
+    /// The `(rows, columns)` of the [`RawTable`] at the last layout.
+    dimensions: (usize, usize),
     cursor_position: Option<Point>,
     motion: Option<Motion>,
+    /// The `(row, column)` a drag selection is anchored to.
+    selection_drag: Option<(usize, usize)>,
+    /// The `(row, column)` kept in place while extending the selection with
+    /// the keyboard.
+    selection_anchor: Option<(usize, usize)>,
+    /// The scroll velocity of the cells while dragging near their edges.
+    auto_scroll: Vector,
+    /// The pixel scroll accumulated over the pagination.
+    pages_scroll: f32,
+    /// Whether the vertical scrollbar is being dragged and the distance from
+    /// the start of its thumb it was grabbed at.
+    scrollbar_drag: Option<(bool, f32)>,
+    touch_pan: Option<TouchPan>,
+    smooth_scroll: Option<SmoothScroll>,
 }
 
 impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
-    /// The maximum number of page numbers displayed
-    const PAGINATION_LIMIT: usize = 11;
-    /// The maximum size of a cell
-    const MAX_CELL: Size = Size::new(f32::INFINITY, 45.0);
-    /// Multiplier for each scroll step
-    const SCROLL_MULT: f32 = 5.0;
+    /// The pixels a wheel line scrolls before the scroll step.
+    const WHEEL_LINE: f32 = 5.0;
+    /// The width of the search input
+    const SEARCH_WIDTH: f32 = 160.0;
     /// Multiplier for column kind text size.
     const KIND_MULT: f32 = 0.9;
+    /// The distance from the cells edges at which dragging starts scrolling.
+    const AUTO_SCROLL_EDGE: f32 = 20.0;
+    /// Multiplier for the auto scroll velocity.
+    const AUTO_SCROLL_MULT: f32 = 0.25;
+    /// The pixel scroll over the pagination needed to change the page.
+    const PAGES_SCROLL_STEP: f32 = 50.0;
+    /// The columns laid out on both sides of those in view.
+    const OVERSCAN: usize = 2;
 
     pub fn new() -> Self {
         Self {
             cells: vec![],
             numbering: vec![],
             headers: vec![],
+            groups: vec![],
+            groups_height: 0.0,
+            sort_arrows: Default::default(),
+            sort_width: 0.0,
             paginations: vec![],
+            compact_pagination: false,
             page_next: Cell::<Renderer>::default(),
             page_back: Cell::<Renderer>::default(),
             goto_input: (Cell::<Renderer>::default(), String::default()),
+            search_input: (Cell::<Renderer>::default(), String::default()),
+            search_placeholder: Cell::<Renderer>::default(),
+            search_page: None,
+            is_stale: false,
+            edited: None,
+            edit_state: None,
             goto_page: Cell::<Renderer>::default(),
             goto_go: Cell::<Renderer>::default(),
+            page_sizes_label: Cell::<Renderer>::default(),
+            page_sizes: vec![],
             status: (Cell::<Renderer>::default(), String::default()),
+            status_segments: (Cell::<Renderer>::default(), Cell::<Renderer>::default()),
+            notice: None,
             pages_padding: Padding::ZERO,
             page: 0,
             page_size: Pixels::ZERO,
@@ -99,13 +225,46 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
             editing: None,
             scroll_offset: Vector::default(),
             cells_dim: Size::default(),
+            frozen_width: 0.0,
+            edit_error: None,
+            shaping: Shaping::Advanced,
+            line_height: LineHeight::default(),
+            cursor_blink: true,
+            cell_gap: CELL_GAP,
+            max_cell: Size::new(f32::INFINITY, MAX_CELL_HEIGHT),
+            footer: Vec::new(),
+            footer_height: 0.0,
             min_widths: vec![],
+            hidden: 0,
             min_heights: vec![],
+            manual_widths: HashMap::new(),
+            filled_widths: HashMap::new(),
+            manual_heights: HashMap::new(),
+            truncated: HashSet::new(),
+            shaped: HashMap::new(),
+            shaped_with: None,
+            cell_ellipsis: Cell::<Renderer>::default(),
+            status_ellipsis: Cell::<Renderer>::default(),
+            placeholders: HashSet::new(),
+            matches: HashSet::new(),
+            overflow_hover: None,
+            tooltip_hover: None,
+            hovered_cell: None,
+            tooltip: None,
+            loading: Cell::<Renderer>::default(),
             resizing: None,
             selection: None,
             page_limit: 0,
+            dimensions: (0, 0),
             cursor_position: None,
             motion: None,
+            selection_drag: None,
+            selection_anchor: None,
+            auto_scroll: Vector::ZERO,
+            pages_scroll: 0.0,
+            scrollbar_drag: None,
+            touch_pan: None,
+            smooth_scroll: None,
         }
     }
 
@@ -115,18 +274,114 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
         font: Renderer::Font,
         text_size: Pixels,
     ) {
-        if self.page_limit == table.page_limit {
+        let dimensions = (table.raw.height(), table.raw.width());
+        let is_limit_changed = self.reconcile_page_limit(table);
+
+        if table.read_only && matches!(self.editing, Some(Editing::Cell { .. })) {
+            self.reset_editing();
+        }
+
+        // Nothing started before loading can be finished during it
+        if table.loading {
+            self.reset_editing();
+            self.resizing = None;
+            self.motion = None;
+            self.selection_drag = None;
+            self.tooltip = None;
+        }
+
+        // Nor can a column being edited be hidden from under it
+        if let Some(Editing::Cell {
+            index, is_header, ..
+        }) = self.editing
+        {
+            let column = if is_header {
+                index
+            } else {
+                index / table.page_limit.max(1)
+            };
+
+            if table.is_hidden(column) {
+                self.reset_editing();
+            }
+        }
+
+        let hidden = (0..dimensions.1)
+            .filter(|column| table.is_hidden(*column))
+            .count();
+
+        let is_numbering_changed = self.numbering.is_empty() == table.numbering;
+        let is_shaping_changed =
+            self.shaping != table.shaping || self.line_height != table.line_height;
+        self.shaping = table.shaping;
+        self.line_height = table.line_height;
+        self.cell_gap = table.cell_gap;
+        self.cursor_blink = table.cursor_blink;
+
+        // Cells shaped with other text settings have to be shaped again
+        let shaped_with = Some((font, text_size));
+        let max_cell = Size::new(f32::INFINITY, table.max_cell_height);
+        if is_shaping_changed || self.shaped_with != shaped_with || self.max_cell != max_cell {
+            self.shaped.clear();
+        }
+        self.shaped_with = shaped_with;
+        self.max_cell = max_cell;
+        let is_pagination_changed =
+            self.paginations.len() != table.pagination_limit.min(table.pages_end() + 1);
+
+        if !is_limit_changed
+            && !is_numbering_changed
+            && !is_shaping_changed
+            && !is_pagination_changed
+            && self.dimensions == dimensions
+            && self.hidden == hidden
+        {
             return;
         }
-        self.page_limit = table.page_limit;
+        self.dimensions = dimensions;
+        self.hidden = hidden;
+
+        // The data may have shrunk from under the current page
+        if self.page > table.pages_end() {
+            self.leave_pinned_heights();
+            self.page = table.pages_end();
+            self.reset_editing();
+        }
+
+        // Or from under the selection, after a removal
+        let is_outside = self
+            .selection
+            .as_ref()
+            .and_then(|selection| selection.bounds())
+            .is_some_and(|(rows, columns)| {
+                *rows.start() >= dimensions.0 || *columns.end() >= dimensions.1
+            });
+
+        if is_outside {
+            self.reset_selection();
+            self.motion = None;
+        }
+
+        if let Some(Editing::Cell {
+            index, is_header, ..
+        }) = self.editing
+        {
+            let len = if is_header {
+                dimensions.1
+            } else {
+                table.page_limit * dimensions.1
+            };
+
+            if index >= len {
+                self.reset_editing();
+            }
+        }
 
         self.pages_padding = Padding::from([2, 6]);
         let size = text_size * 7.0 / 8.0;
         self.page_size = size;
 
-        let dimensions = (table.raw.height(), table.raw.width());
-
-        self.headers = (0..dimensions.0)
+        self.headers = (0..dimensions.1)
             .map(|_| (Cell::<Renderer>::default(), Cell::<Renderer>::default()))
             .collect();
 
@@ -143,75 +398,278 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
             self.min_widths.truncate(dimensions.1 + 1);
         }
 
-        let height_len = self.min_heights.len();
-        if height_len == 0 {
-            self.min_heights = vec![0.0f32; limit + 1];
-        } else if height_len < limit + 1 {
-            let diff = limit + 1 - height_len;
-            let mut extra = vec![0.0f32; diff];
-            self.min_heights.append(&mut extra);
-        } else if height_len > limit + 1 {
-            self.min_heights.truncate(limit + 1);
+        self.min_heights.resize(limit + 1, 0.0);
+
+        self.manual_widths
+            .retain(|column, _| *column < dimensions.1 + 1);
+        self.manual_heights.retain(|row, _| *row < dimensions.0 + 1);
+
+        for (column, width) in table.column_widths.iter().enumerate().take(dimensions.1) {
+            if let Some(width) = width {
+                self.manual_widths
+                    .entry(column + 1)
+                    .or_insert(width.max(0.0));
+            }
         }
 
-        self.numbering = (0..limit + 1)
-            .map(|_| Cell::<Renderer>::default())
-            .collect();
+        let numbering = if table.numbering { limit + 1 } else { 0 };
+        self.numbering
+            .resize_with(numbering, Cell::<Renderer>::default);
 
         let pages_end = table.pages_end() + 1;
 
-        self.paginations = (0..Self::PAGINATION_LIMIT.min(pages_end))
+        self.paginations = (0..table.pagination_limit.min(pages_end))
             .map(|_| (Cell::<Renderer>::default(), String::default()))
             .collect();
 
         self.status = {
             let value = match table.status.as_ref() {
                 Some(status) => status.clone(),
+                None if hidden > 0 => format!(
+                    "{} rows × {} columns ({hidden} hidden)",
+                    dimensions.0, dimensions.1
+                ),
                 None => format!("{} rows × {} columns", dimensions.0, dimensions.1),
             };
-            let text =
-                super::text::<Renderer>(&value, Self::MAX_CELL, font, Horizontal::Left, size);
+            let text = super::text::<Renderer>(
+                &value,
+                self.max_cell,
+                font,
+                Horizontal::Left,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             (Cell::<Renderer>::new(text), value)
         };
 
-        self.cells = (0..limit * dimensions.1)
-            .map(|_| Cell::<Renderer>::default())
-            .collect();
+        self.cells
+            .resize_with(limit * dimensions.1, Cell::<Renderer>::default);
+        self.shaped.clear();
 
         self.page_back = {
-            let text =
-                super::text::<Renderer>(BACK, Self::MAX_CELL, font, Horizontal::Center, size);
+            let text = super::text::<Renderer>(
+                BACK,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             Cell::<Renderer>::new(text)
         };
 
         self.page_next = {
-            let text =
-                super::text::<Renderer>(NEXT, Self::MAX_CELL, font, Horizontal::Center, size);
+            let text = super::text::<Renderer>(
+                NEXT,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             Cell::<Renderer>::new(text)
         };
 
         self.goto_page = {
-            let text =
-                super::text::<Renderer>(GOTO_PAGE, Self::MAX_CELL, font, Horizontal::Center, size);
+            let text = super::text::<Renderer>(
+                GOTO_PAGE,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             Cell::<Renderer>::new(text)
         };
 
         self.goto_go = {
-            let text =
-                super::text::<Renderer>(GOTO_GO, Self::MAX_CELL, font, Horizontal::Center, size);
+            let text = super::text::<Renderer>(
+                GOTO_GO,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
+            Cell::<Renderer>::new(text)
+        };
+
+        self.page_sizes_label = {
+            let text = super::text::<Renderer>(
+                PAGE_SIZES,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             Cell::<Renderer>::new(text)
         };
 
         self.goto_input = {
             let value = (self.page + 1).to_string();
-            let text =
-                super::text::<Renderer>(&value, Self::MAX_CELL, font, Horizontal::Center, size);
+            let text = super::text::<Renderer>(
+                &value,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                size,
+                self.shaping,
+                self.line_height,
+            );
             (Cell::<Renderer>::new(text), value)
         };
 
         self.pages_gap = 5.0;
     }
 
+    /// Clears the dimensions of the column and/or row of `resize` so the next
+    /// layout fits them to their contents.
+    fn auto_fit(&mut self, resize: Resizing) {
+        match resize.direction() {
+            ResizeDirection::Horizontal => self.fit_column(resize.column),
+            ResizeDirection::Vertical => self.fit_row(resize.row),
+            ResizeDirection::Diagonal => {
+                self.fit_column(resize.column);
+                self.fit_row(resize.row);
+            }
+        }
+    }
+
+    fn fit_column(&mut self, column: usize) {
+        self.manual_widths.remove(&column);
+        self.min_widths[column] = 0.0;
+    }
+
+    fn fit_row(&mut self, row: usize) {
+        self.manual_heights.remove(&self.height_key(row));
+        self.min_heights[row] = 0.0;
+    }
+
+    /// Stores the dimensions of `resize` as manually set, so later layouts
+    /// keep them regardless of the cell contents.
+    fn pin(&mut self, resize: &Resizing, size: Size) {
+        let (width, height) = match resize.direction() {
+            ResizeDirection::Horizontal => (true, false),
+            ResizeDirection::Vertical => (false, true),
+            ResizeDirection::Diagonal => (true, true),
+        };
+
+        if width {
+            self.manual_widths.insert(resize.column, size.width);
+        }
+
+        if height {
+            self.manual_heights
+                .insert(self.height_key(resize.row), size.height);
+        }
+    }
+
+    /// Clears the layout heights of the pinned rows of the current page before
+    /// leaving it, so they stay behind with their rows.
+    fn leave_pinned_heights(&mut self) {
+        for row in 1..self.min_heights.len() {
+            if self.manual_heights.contains_key(&self.height_key(row)) {
+                self.min_heights[row] = 0.0;
+            }
+        }
+    }
+
+    /// Returns the key of the layout `row` in the manual heights, which count
+    /// the data rows from the first page so pinned heights stay with their
+    /// rows across pages. The headers row is always `0`.
+    fn height_key(&self, row: usize) -> usize {
+        if row == 0 {
+            0
+        } else {
+            self.page * self.page_limit + row
+        }
+    }
+
+    /// Drags the column and/or row of `resize` to `position`, pinning their
+    /// new dimensions and publishing an [`Action::Resize`].
+    ///
+    /// Returns the scroll keeping the dragged edge under the cursor.
+    fn drag_resize<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        resize: &mut Resizing,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) -> Vector {
+        let width = self.min_widths[resize.column];
+        let height = self.min_heights[resize.row];
+        let (new, diff) = resize.drag(position, width, height);
+
+        self.min_widths[resize.column] = new.width;
+        self.min_heights[resize.row] = new.height;
+        self.pin(resize, new);
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = resize.action(new, self.page * table.page_limit);
+            let msg = on_action(action);
+
+            shell.publish(msg);
+        }
+
+        diff
+    }
+
+    /// Grows the layout `column` and `row` to fit `bounds`, up to `max_width`,
+    /// leaving manually resized dimensions untouched. Returns whether anything
+    /// grew.
+    fn grow_to_fit(&mut self, row: usize, column: usize, bounds: Size, max_width: f32) -> bool {
+        let mut grew = false;
+        let width = bounds.width.min(max_width);
+
+        if !self.manual_widths.contains_key(&column) && width > self.min_widths[column] {
+            self.min_widths[column] = width;
+            grew = true;
+        }
+
+        if !self.manual_heights.contains_key(&self.height_key(row))
+            && bounds.height > self.min_heights[row]
+        {
+            self.min_heights[row] = bounds.height;
+            grew = true;
+        }
+
+        grew
+    }
+
+    /// Reconciles the [`State`] with the page limit of the [`Table`],
+    /// returning `true` if it changed.
+    ///
+    /// The current page is recomputed so the row at the top of the old page
+    /// stays visible. The scroll offset and column widths are kept.
+    fn reconcile_page_limit<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+    ) -> bool {
+        if self.page_limit == table.page_limit {
+            return false;
+        }
+
+        // The pinned rows are keyed by the old page and limit
+        self.leave_pinned_heights();
+
+        if self.page_limit != 0 && table.page_limit != 0 {
+            let first_row = self.page * self.page_limit;
+            self.page = (first_row / table.page_limit).min(table.pages_end());
+        }
+
+        self.page_limit = table.page_limit;
+        self.goto_input.1 = (self.page + 1).to_string();
+
+        true
+    }
+
     fn swap_dimensions(&mut self, motion: Motion, page_limit: usize) {
         match motion {
             Motion::Cell {
@@ -228,18 +686,26 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
 
                 self.min_heights.swap(s_row, d_row);
                 self.min_widths.swap(s_column, d_column);
+                let (s_key, d_key) = (self.height_key(s_row), self.height_key(d_row));
+                swap_entries(&mut self.manual_heights, s_key, d_key);
+                swap_entries(&mut self.manual_widths, s_column, d_column);
+                swap_entries(&mut self.filled_widths, s_column, d_column);
             }
             Motion::Row { src, dst } => {
                 let src = src.saturating_sub(self.page * page_limit) + 1;
                 let dst = dst.saturating_sub(self.page * page_limit) + 1;
 
                 self.min_heights.swap(src, dst);
+                let (src_key, dst_key) = (self.height_key(src), self.height_key(dst));
+                swap_entries(&mut self.manual_heights, src_key, dst_key);
             }
             Motion::Column { src, dst } => {
                 let src = src + 1;
                 let dst = dst + 1;
 
                 self.min_widths.swap(src, dst);
+                swap_entries(&mut self.manual_widths, src, dst);
+                swap_entries(&mut self.filled_widths, src, dst);
             }
         };
     }
@@ -258,17 +724,58 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
         self.reset_editing();
         self.reset_selection();
         self.motion = None;
+        self.selection_drag = None;
         self.last_click = None;
         self.is_focused = None;
         self.keyboard_modifiers = keyboard::Modifiers::default()
     }
 
+    /// Focuses the [`Table`], restarting the cursor blink.
+    fn focus(&mut self) {
+        let now = Instant::now();
+        let focus = self.is_focused.get_or_insert(Focus {
+            updated_at: now,
+            now,
+            is_window_focused: true,
+        });
+        focus.updated_at = now;
+    }
+
     fn reset_editing(&mut self) {
+        // Half-typed page numbers are dropped unless submitted first
+        if matches!(self.editing, Some(Editing::Goto(_))) {
+            self.goto_input.1 = (self.page + 1).to_string();
+        }
+
         self.is_text_dragging = false;
         self.editing = None;
+        self.edit_error = None;
         self.cursor = utils::Cursor::default();
     }
 
+    /// Validates the cell being edited, relaying out the status area when its
+    /// error changes.
+    pub fn revalidate<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let error = match &self.editing {
+            Some(Editing::Cell {
+                index,
+                value,
+                is_header: false,
+                ..
+            }) => table.edit_error(value, index / table.page_limit),
+            _ => None,
+        };
+
+        if error != self.edit_error {
+            self.edit_error = error;
+            shell.invalidate_layout();
+        }
+    }
+
     fn reset_resizing(&mut self) {
         self.resizing = None;
     }
@@ -277,2246 +784,5917 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
         self.selection = None;
     }
 
-    fn scroll_cells(&mut self, viewport: Size, offset: Vector) {
-        let offset = offset * Self::SCROLL_MULT;
-        let new = self.scroll_offset + offset;
+    /// Submits the header or data cell being edited, relaying out the cells.
+    /// Invalid data cell edits are discarded instead.
+    fn submit_editing<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(Editing::Cell {
+            index,
+            value,
+            is_header,
+            ..
+        }) = self
+            .editing
+            .take_if(|editing| matches!(editing, Editing::Cell { .. }))
+        else {
+            return;
+        };
 
-        let width_diff = (viewport.width - self.cells_dim.width).min(0.0);
-        let height_diff = (viewport.height - self.cells_dim.height).min(0.0);
+        if is_header {
+            if let Some(on_action) = table.on_action.as_ref() {
+                let action = Action::header_submit(value, index);
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
+            self.edited = None;
+        } else if self.edit_error.is_none() {
+            let (row, column) = (index % table.page_limit, index / table.page_limit);
+            let row = row + (self.page * table.page_limit);
+
+            if let Some(on_action) = table.on_action.as_ref() {
+                let value = table.submitted(value, column);
+                let action = Action::cell_submit(value, column, row);
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
+            self.edited = None;
+        }
 
-        self.scroll_offset =
-            Vector::new(new.x.clamp(width_diff, 0.0), new.y.clamp(height_diff, 0.0));
+        self.reset_editing();
+        shell.invalidate_layout();
     }
 
-    fn layout_cells<Raw: RawTable, Message, Theme: Catalog>(
+    /// Submits the cell being edited at `row` and `column` then selects the
+    /// cell below it, or above it when `down` is false, clamped to the
+    /// current page.
+    fn submit_vertical<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &Renderer,
-        font: Renderer::Font,
-    ) -> Node {
-        let header_font = table.header_font.unwrap_or_else(|| renderer.default_font());
-        let numbering_font = table
-            .numbering_font
-            .unwrap_or_else(|| renderer.default_font());
-        let padding = table.cell_padding;
-        let size = table.text_size.unwrap_or_else(|| renderer.default_size());
-
-        let gap = CELL_GAP;
-        // Adds numbering column
-        let dimensions = (table.rows, table.cols + 1);
-        // Adds headers row
-        let page_limit = table.page_limit + 1;
+        value: String,
+        row: usize,
+        column: usize,
+        down: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.edit_error.is_some() {
+            return;
+        }
 
-        let numbering_max = dimensions.0;
-        let numbering_max = Cell::<Renderer>::new(super::text::<Renderer>(
-            &numbering_max.to_string(),
-            Self::MAX_CELL,
-            font,
-            Horizontal::Right,
-            size,
-        ))
-        .min_bounds()
-        .expand(padding);
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = Action::cell_submit(table.submitted(value, column), column, row);
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
+        self.edited = None;
 
-        let total = dimensions.1 * page_limit;
-        let mut knds_height = vec![];
-        let mut curr = 0;
+        let (page_start, page_end) = self.page_rows(table).into_inner();
 
-        // Prep stage. Fill the paragraphs, register the dimensions
-        while curr < total {
-            let row = curr % page_limit;
-            let column = curr / page_limit;
+        let mut selection = Selection::new(row, column);
+        if down {
+            selection.move_down(page_end);
+        } else if row > page_start {
+            selection.move_up();
+        }
 
-            let size = if column != 0 {
-                let column = column - 1;
-                let kind = table.raw.column_kind(column).expect("Missing table column");
-                let horizontal = table.raw.kind_alignment(&kind);
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = Action::Selection(selection.clone());
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
 
-                if row == 0 {
-                    let (header, knd) = &mut self.headers[column];
-                    let label = match self.editing.as_ref() {
-                        Some(Editing::Cell {
-                            index,
-                            value,
-                            is_header: true,
-                            ..
-                        }) if *index == column => value,
-                        _ => &table.raw.column_header(column).unwrap_or_default(),
-                    };
-                    let kind = kind.to_string();
+        self.reset_editing();
+        self.selection = Some(selection);
+        shell.invalidate_layout();
+    }
 
-                    let text = super::text::<Renderer>(
-                        label,
-                        Self::MAX_CELL,
-                        header_font,
-                        Horizontal::Center,
-                        size,
-                    );
-                    header.update(text);
-                    let text = super::text::<Renderer>(
-                        &kind,
-                        Self::MAX_CELL,
-                        header_font,
-                        Horizontal::Center,
-                        size * Self::KIND_MULT,
-                    );
-                    knd.update(text);
+    /// Submits the cell being edited at `row` and `column` then starts editing
+    /// the next cell, or the previous one when `forward` is false, with its
+    /// contents selected. Rows wrap within the current page.
+    fn submit_horizontal<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        value: String,
+        row: usize,
+        column: usize,
+        forward: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.edit_error.is_some() {
+            return;
+        }
 
-                    let header = header.min_bounds();
-                    let knd = knd.min_bounds();
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = Action::cell_submit(table.submitted(value, column), column, row);
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
+        self.edited = None;
 
-                    knds_height.push(knd.height);
-                    Size::new(header.width.max(knd.width), header.height + knd.height)
-                } else {
-                    let row = row - 1;
-                    let idx = (column * table.page_limit) + (row % table.page_limit);
-                    let paragraph = &mut self.cells[idx];
-                    let row = row + (self.page * (page_limit - 1));
+        let (page_start, page_end) = self.page_rows(table).into_inner();
+        let column_limit = table.cols.saturating_sub(1);
 
-                    let value = match self.editing.as_ref() {
-                        Some(Editing::Cell {
-                            index,
-                            value,
-                            is_header: false,
-                            ..
-                        }) if *index == idx => value,
-                        _ => &table.raw.cell(row, column).unwrap_or_default(),
-                    };
+        // Hidden columns are stepped over, without leaving the page
+        let mut selection = Selection::new(row, column);
+        if forward {
+            table.step_shown(&mut selection, |selection| {
+                selection.move_next(page_end, column_limit)
+            });
+        } else {
+            table.step_shown(&mut selection, |selection| {
+                if selection.active() != (page_start, 0) {
+                    selection.move_previous(column_limit)
+                }
+            });
+        }
 
-                    let text =
-                        super::text::<Renderer>(value, Self::MAX_CELL, font, horizontal, size);
-                    paragraph.update(text);
+        let (row, column) = selection.active();
+        let value = table
+            .raw
+            .cell(row, column)
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = Action::Selection(selection.clone());
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
 
-                    paragraph.min_bounds()
-                }
-            } else if row != 0 {
-                let paragraph = &mut self.numbering[row];
-                let row = (row - 1) + (table.page_limit * self.page);
+        self.reset_editing();
+        self.selection = Some(selection);
+        shell.invalidate_layout();
 
-                paragraph.update(super::text::<Renderer>(
-                    &row.to_string(),
-                    Self::MAX_CELL,
-                    numbering_font,
-                    Horizontal::Right,
-                    size,
-                ));
+        // Locked cells are only selected
+        if !table.is_editable(false, column) {
+            return;
+        }
 
-                paragraph.min_bounds()
-            } else {
-                Size::ZERO
-            }
-            .expand(padding);
+        self.cursor.select_all(&value);
+        self.editing = Some(Editing::Cell {
+            index: (column * table.page_limit) + (row - page_start),
+            value,
+            is_header: false,
+            history: History::default(),
+        });
+    }
 
-            let height = self.min_heights[row].max(size.height);
-            self.min_heights[row] = height;
+    /// Moves the [`Table`] to the one-based page typed in the goto input.
+    ///
+    /// An empty input or `0` goes to the first page while numbers past the
+    /// page count go to the last page.
+    fn submit_goto<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (_, value) = &self.goto_input;
 
-            let width = if column == 0 {
-                numbering_max.width
-            } else {
-                self.min_widths[column].max(size.width)
-            };
-            self.min_widths[column] = width;
+        let page = match value.parse::<usize>() {
+            Ok(page) => page.saturating_sub(1),
+            Err(_) if value.is_empty() => 0,
+            // The input only accepts digits so this can only be an overflow
+            Err(_) => table.pages_end(),
+        };
 
-            curr += 1;
-        }
+        self.change_page(table, page, shell);
+    }
 
-        curr = 0;
+    /// Moves the [`Table`] to `page`, clamped to the last page.
+    ///
+    /// An [`Action::PageChange`] is only published when the page actually
+    /// changes, in which case the scroll offset may also be reset.
+    fn change_page<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        page: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(previous) = self.set_page(table, page) else {
+            return;
+        };
 
-        let mut offset_width = 0.0;
-        let mut offset_height = 0.0;
-        let mut headers_x = 0.0;
-        let mut numbering_y = 0.0;
-        let mut children = vec![];
-        let mut headers = vec![];
-        let mut numbering = vec![];
+        self.publish_page_change(table, previous, shell);
+    }
 
-        // Create the layout nodes
-        while curr < total {
-            let row = curr % page_limit;
-            let column = curr / page_limit;
+    /// Publishes the move from the `previous` page to the current one, both
+    /// as an [`Action::PageChange`] and through [`Table::on_page_change`].
+    fn publish_page_change<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        previous: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(on_action) = table.on_action.as_ref() {
+            let action = Action::page(previous, self.page);
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
 
-            if column != 0 {
-                if row == 0 {
-                    let height = self.min_heights[row];
-                    let width = self.min_widths[column];
-                    let knd_height = knds_height[column - 1];
-                    let label = Size::new(
-                        width - padding.horizontal(),
-                        height - padding.vertical() - knd_height,
-                    );
-                    let knd = Size::new(width - padding.horizontal(), knd_height);
-                    let knd = Node::new(knd).translate([padding.left, label.height + padding.top]);
-                    let label = Node::new(label).translate([padding.left, padding.top]);
+        if let Some(on_page_change) = table.on_page_change.as_ref() {
+            shell.publish(on_page_change(previous, self.page));
+        }
+    }
 
-                    let size = Size::new(width, height);
-                    let node = Node::with_children(size, vec![label, knd]);
+    /// Moves the [`Table`] to `page`, clamped to the last page, without
+    /// publishing an [`Action`].
+    ///
+    /// Returns the previous page if the page changed.
+    fn set_page<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        page: usize,
+    ) -> Option<usize> {
+        let page = page.min(table.pages_end());
+        self.goto_input.1 = (page + 1).to_string();
 
-                    let size = size + Size::from([gap, gap]);
-                    let node = Node::with_children(size, vec![node]).translate([headers_x, 0.0]);
+        if page == self.page {
+            return None;
+        }
 
-                    headers_x += size.width;
-                    headers.push(node);
-                } else {
-                    let size = Size::new(self.min_widths[column], self.min_heights[row]);
-                    let node = Node::new(size);
+        // Edited data cells belong to the previous page
+        if matches!(
+            self.editing,
+            Some(Editing::Cell {
+                is_header: false,
+                ..
+            })
+        ) {
+            self.reset_editing();
+        }
 
-                    let size = size + Size::from([gap, gap]);
-                    let node = Node::with_children(size, vec![node])
-                        .translate([offset_width, offset_height]);
+        self.leave_pinned_heights();
 
-                    if (curr + 1) / page_limit == column {
-                        offset_height += size.height;
-                    } else {
-                        offset_height = 0.0;
-                        offset_width += size.width;
-                    }
+        let previous = self.page;
+        self.page = page;
 
-                    children.push(node);
-                }
-            } else {
-                let size = Size::new(self.min_widths[column], self.min_heights[row]);
-                let node = Node::new(size);
+        if table.reset_scroll_on_page_change {
+            self.scroll_offset.y = 0.0;
 
-                let size = size + Size::from([gap, gap]);
-                let node = Node::with_children(size, vec![node]).translate([0.0, numbering_y]);
-                numbering_y += size.height;
-                numbering.push(node);
+            if !table.keep_horizontal_scroll {
+                self.scroll_offset.x = 0.0;
             }
-
-            curr += 1;
         }
 
-        let numbering = {
-            let width = numbering
-                .first()
-                .map(|node| node.size().width)
-                .unwrap_or_default();
-            let height = numbering_y;
-
-            let size = Size::new(width, height);
-
-            Node::with_children(size, numbering).translate([0.0, self.scroll_offset.y])
-        };
-
-        let headers = {
-            let width = headers_x;
-            let height = headers
-                .first()
-                .map(|node| node.size().height)
-                .unwrap_or_default();
+        Some(previous)
+    }
 
-            let size = Size::new(width, height);
-            Node::with_children(size, headers)
-                .translate([numbering.size().width + self.scroll_offset.x, 0.])
-        };
+    /// Applies a [`Request`] made through a widget operation.
+    pub fn on_request<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        request: Request,
+    ) {
+        match request {
+            Request::Reveal {
+                row,
+                column,
+                select,
+            } => {
+                if table.rows == 0 || table.cols == 0 {
+                    return;
+                }
 
-        let total_height = self
-            .min_heights
-            .iter()
-            .enumerate()
-            .filter(|(idx, _)| *idx != 0)
-            .fold(0.0, |acc, (_, curr)| acc + curr + gap);
+                let (row, column) = (row.min(table.rows - 1), column.min(table.cols - 1));
 
-        let total_width = self
-            .min_widths
-            .iter()
-            .enumerate()
-            .filter(|(idx, _)| *idx != 0)
-            .fold(0.0, |acc, (_, curr)| acc + curr + gap);
+                if select {
+                    self.reset_editing();
+                    self.motion = None;
+                    self.selection = Some(Selection::new(row, column));
+                }
 
-        let size = Size::new(total_width, total_height);
-        self.cells_dim = size;
+                self.set_page(table, row / table.page_limit.max(1));
 
-        let cells = Node::with_children(size, children).translate(Vector::new(
-            self.scroll_offset.x + numbering.size().width,
-            self.scroll_offset.y + headers.size().height,
-        ));
+                if let Some(cells) = layout.children().next() {
+                    let viewport = self.cells_viewport(layout.bounds(), table.padding, cells);
+                    self.reveal(table, row, column, viewport.size());
+                }
 
-        let size = {
-            let width = numbering.size().width + headers.size().width.max(cells.size().width);
-            let height = (headers.size().height + cells.size().height).max(numbering.size().height);
+                self.is_stale = true;
+            }
+            Request::GoToPage(page) => {
+                self.set_page(table, page);
+                self.is_stale = true;
+            }
+        }
+    }
 
-            Size::new(width, height)
-        };
-        Node::with_children(size, vec![numbering, headers, cells])
+    /// Returns the paragraph and value of the goto input, or the search input
+    /// if `is_search`.
+    fn input(&self, is_search: bool) -> &(Cell<Renderer>, String) {
+        if is_search {
+            &self.search_input
+        } else {
+            &self.goto_input
+        }
     }
 
-    fn layout_pagination<Raw: RawTable, Message, Theme: Catalog>(
+    /// Publishes an [`Action::Search`] for the current query.
+    ///
+    /// Starting a search moves to the first page, and clearing it returns to
+    /// the page shown before.
+    fn search<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
-        font: Renderer::Font,
-    ) -> Node {
-        if table.raw.is_empty() {
-            return Node::with_children(Size::ZERO, vec![Node::default(); 3]);
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let query = self.search_input.1.clone();
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            let msg = on_action(Action::Search(query.clone()));
+            shell.publish(msg);
         }
 
-        let gap = self.pages_gap;
-        let pages_end = table.pages_end() + 1;
-        let current_page = self.page + 1;
+        match self.search_page {
+            None if !query.is_empty() => {
+                self.search_page = Some(self.page);
+                self.change_page(table, 0, shell);
+            }
+            Some(page) if query.is_empty() => {
+                self.search_page = None;
 
-        let pages = if pages_end <= Self::PAGINATION_LIMIT {
-            (1..=pages_end)
-                .map(|num| num.to_string())
-                .collect::<Vec<String>>()
-        } else {
-            gen_pagination(1, pages_end as isize, current_page as isize)
-        };
+                // The rows of the page may not be restored yet
+                self.leave_pinned_heights();
 
-        let mut min_bounds = Size::ZERO;
+                let previous = self.page;
+                self.page = page;
+                self.goto_input.1 = (page + 1).to_string();
 
-        // Update paragraphs, register min width
-        for (page, (cell, content)) in pages.into_iter().zip(self.paginations.iter_mut()) {
-            let text = super::text::<Renderer>(
-                &page,
-                Self::MAX_CELL,
-                font,
-                Horizontal::Center,
-                self.page_size,
-            );
-            cell.update(text);
-            *content = page;
+                if previous != page {
+                    self.publish_page_change(table, previous, shell);
+                }
+            }
+            _ => {}
+        }
 
-            let size = cell.min_bounds().expand(self.pages_padding);
+        self.reset_selection();
+        shell.invalidate_layout();
+    }
 
-            min_bounds = min_bounds.max(size);
-        }
+    /// Briefly shows `notice` in place of the default status.
+    fn notify<Message>(&mut self, notice: String, shell: &mut Shell<'_, Message>) {
+        let now = Instant::now();
+        self.notice = Some((notice, now));
 
-        let back = self.page_back.min_bounds().expand(self.pages_padding);
-        let next = self.page_next.min_bounds().expand(self.pages_padding);
+        shell.invalidate_layout();
+        shell.request_redraw(window::RedrawRequest::At(now + NOTICE_DURATION));
+    }
 
-        let mut pages = vec![];
-        let mut offset = 0.0;
+    /// Returns the absolute data rows of the current page.
+    fn page_rows<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+    ) -> RangeInclusive<usize> {
+        let page_start = self.page * table.page_limit;
+        let page_end = (page_start + table.page_limit)
+            .min(table.rows)
+            .saturating_sub(1);
 
-        // Create layout nodes
-        for _ in 0..self.paginations.len() {
-            let node = Node::new(min_bounds).translate(Vector::new(offset, 0.0));
+        page_start..=page_end
+    }
 
-            pages.push(node);
-            offset += min_bounds.width + gap;
+    /// Publishes an empty [`Action::CellSubmit`] for every selected data cell
+    /// which can be edited, skipping locked, hidden and checkbox columns.
+    /// Read-only tables clear nothing.
+    ///
+    /// Returns the number of cleared cells.
+    fn clear_selected<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) -> usize {
+        let Some(selection) = self.selection.as_ref().filter(|_| !table.read_only) else {
+            return 0;
+        };
+
+        let mut cells = selection
+            .list()
+            .into_iter()
+            .filter(|(row, column)| {
+                *row < table.rows && *column < table.cols && table.is_editable(false, *column)
+            })
+            .collect::<Vec<(usize, usize)>>();
+        cells.sort_unstable();
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            for (row, column) in cells.iter() {
+                let action = Action::cell_submit(String::new(), *column, *row);
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
         }
 
-        let mut total_width = min_bounds.width * pages.len() as f32;
+        cells.len()
+    }
 
-        if !pages.is_empty() {
-            total_width += gap * (pages.len() - 1) as f32;
+    /// Publishes an [`Action::CellSubmit`] flipping the checkmark at `row`
+    /// and `column`.
+    fn toggle_cell<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        row: usize,
+        column: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(on_action) = table.on_action.as_ref() {
+            let value = (!table.is_checked(row, column)).to_string();
+            let action = Action::cell_submit(value, column, row);
+            let msg = on_action(action);
+            shell.publish(msg);
         }
+    }
 
-        let mut offset_x = 0.0;
-        let offset_y = 0.0;
+    fn scroll_cells(&mut self, viewport: Size, offset: Vector) {
+        self.scroll_offset = self.clamp_scroll(viewport, self.scroll_offset + offset);
+    }
 
-        let back = Node::new(back).translate(Vector::new(offset_x, offset_y));
+    /// Clamps the scroll `offset` to the cells overflowing the `viewport`.
+    fn clamp_scroll(&self, viewport: Size, offset: Vector) -> Vector {
+        let width_diff = (viewport.width - self.cells_dim.width).min(0.0);
+        let height_diff = (viewport.height - self.cells_dim.height).min(0.0);
 
-        offset_x += back.size().width + (gap * 1.5);
+        Vector::new(
+            offset.x.clamp(width_diff, 0.0),
+            offset.y.clamp(height_diff, 0.0),
+        )
+    }
 
-        let pages = Node::with_children(Size::new(total_width, min_bounds.height), pages)
-            .translate(Vector::new(offset_x, offset_y));
+    /// Scrolls the cells so the data cell at `row` and `column` is inside the
+    /// cells `viewport`.
+    fn reveal<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        row: usize,
+        column: usize,
+        viewport: Size,
+    ) {
+        let gap = self.cell_gap;
+        let row = row % table.page_limit.max(1);
+
+        let span = |sizes: &[f32], index: usize| {
+            let start = sizes
+                .iter()
+                .skip(1)
+                .take(index)
+                .map(|size| size + gap)
+                .sum::<f32>();
+            let size = sizes.get(index + 1).map_or(0.0, |size| size + gap);
+
+            (start, size)
+        };
 
-        offset_x += pages.size().width + (gap * 1.5);
+        // Offsets are negative, with the start of the cells at zero. Nothing
+        // is visible before `lead`
+        let fit = |offset: f32, (start, size): (f32, f32), view: f32, lead: f32| {
+            if start + offset < lead {
+                lead - start
+            } else if start + size + offset > view {
+                (view - start - size).max(lead - start)
+            } else {
+                offset
+            }
+        };
 
-        let next = Node::new(next).translate(Vector::new(offset_x, offset_y));
+        let columns = (
+            (0..column)
+                .map(|column| self.column_span(table, column))
+                .sum::<f32>(),
+            self.column_span(table, column),
+        );
 
-        offset_x += next.size().width;
+        let x = if column < table.frozen_columns {
+            self.scroll_offset.x
+        } else {
+            fit(
+                self.scroll_offset.x,
+                columns,
+                viewport.width,
+                self.frozen_width,
+            )
+        };
 
-        let total_size = Size::new(
-            offset_x,
-            back.size()
-                .height
-                .max(pages.size().height.max(next.size().height)),
+        self.scroll_offset = Vector::new(
+            x,
+            fit(
+                self.scroll_offset.y,
+                span(&self.min_heights, row),
+                viewport.height,
+                0.0,
+            ),
         );
 
-        Node::with_children(total_size, vec![back, pages, next])
+        self.scroll_cells(viewport, Vector::ZERO);
     }
 
-    fn layout_goto<Raw: RawTable, Message, Theme: Catalog>(
+    /// Selects the next data cell matching the highlighted query after the
+    /// active cell, or the previous one if not `forward`.
+    ///
+    /// Returns `false` if no cell matches.
+    fn select_match<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
-        font: Renderer::Font,
-    ) -> Node {
-        if table.raw.is_empty() {
-            return Node::with_children(Size::ZERO, vec![Node::default(); 3]);
-        }
-        let max = Cell::<Renderer>::new(super::text::<Renderer>(
-            &(table.pages_end() + 1).to_string(),
-            Self::MAX_CELL,
-            font,
-            Horizontal::Right,
-            self.page_size,
-        ));
+        forward: bool,
+        viewport: Size,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let total = table.rows * table.cols;
+        let start = self.selection.as_ref().map(|selection| {
+            let (row, column) = selection.active();
+            (row * table.cols + column).min(total.saturating_sub(1))
+        });
+
+        let found = (1..=total)
+            .map(|step| match start {
+                Some(start) if forward => (start + step) % total,
+                Some(start) => (start + total - step) % total,
+                None if forward => step - 1,
+                None => total - step,
+            })
+            .map(|idx| (idx / table.cols, idx % table.cols))
+            .find(|(row, column)| table.is_match(&table.display_cell(*row, *column)));
+
+        let Some((row, column)) = found else {
+            return false;
+        };
 
-        let page = self.goto_page.min_bounds().expand(self.pages_padding);
-        let go = self.goto_go.min_bounds().expand(self.pages_padding);
+        self.reset_editing();
+        self.motion = None;
+        self.selection = Some(Selection::new(row, column));
 
-        let (input, value) = &mut self.goto_input;
+        if let Some(on_action) = table.on_action.as_ref() {
+            // Guaranteed by the Selection::new above
+            let action = Action::Selection(self.selection.clone().unwrap());
+            let msg = on_action(action);
+            shell.publish(msg);
+        }
 
-        input.update(super::text::<Renderer>(
-            value,
-            Self::MAX_CELL,
-            font,
-            Horizontal::Right,
-            self.page_size,
-        ));
+        self.change_page(table, row / table.page_limit.max(1), shell);
+        self.reveal(table, row, column, viewport);
 
-        let min_bounds = max.min_bounds();
-        let input = Size::new(min_bounds.width + 5.0, min_bounds.height).expand(self.pages_padding);
+        true
+    }
 
-        let mut offset = 0.0;
+    /// Returns the bounds of the numbering, headers and cells, between the
+    /// status and the footer.
+    fn cells_area(
+        &self,
+        bounds: Rectangle,
+        padding: Padding,
+        cells: layout::Layout<'_>,
+    ) -> Rectangle {
+        let (x, y) = (bounds.x + padding.left, cells.bounds().y);
 
-        let page = Node::new(page).translate(Vector::new(offset, 0.0));
+        let width = bounds.x + bounds.width - padding.right - x;
+        let height = bounds.y + bounds.height - padding.bottom - self.footer_height - y;
 
-        offset += page.size().width + (self.pages_gap * 1.5);
+        Rectangle::new(Point::new(x, y), Size::new(width.max(0.0), height.max(0.0)))
+    }
 
-        let input = Node::new(input).translate(Vector::new(offset, 0.0));
+    /// Returns the bounds of the scrollable cells, excluding the numbering and
+    /// headers.
+    fn cells_viewport(
+        &self,
+        bounds: Rectangle,
+        padding: Padding,
+        cells: layout::Layout<'_>,
+    ) -> Rectangle {
+        let mut children = cells.children();
+        let numbering = children.next().map(|numbering| numbering.bounds().width);
+        let headers = children.next().map(|headers| headers.bounds().height);
+
+        let area = self.cells_area(bounds, padding, cells);
+        let shift = Size::new(numbering.unwrap_or_default(), headers.unwrap_or_default());
+
+        Rectangle::new(
+            area.position() + Vector::new(shift.width, shift.height),
+            Size::new(
+                (area.width - shift.width).max(0.0),
+                (area.height - shift.height).max(0.0),
+            ),
+        )
+    }
 
-        offset += input.size().width + (self.pages_gap * 1.5);
+    /// Returns the vertical and horizontal [`Scrollbar`]s of the cells
+    /// `viewport`, if the cells overflow it.
+    fn scrollbars<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        viewport: Rectangle,
+    ) -> [Option<Scrollbar>; 2] {
+        let width = table.scrollbar_width;
 
-        let go = Node::new(go).translate(Vector::new(offset, 0.0));
+        [
+            Scrollbar::vertical(viewport, self.cells_dim.height, self.scroll_offset.y, width),
+            Scrollbar::horizontal(viewport, self.cells_dim.width, self.scroll_offset.x, width),
+        ]
+    }
 
-        offset += go.size().width;
+    /// Scrolls the cells towards `position` when it is near or past the edges
+    /// of the cells viewport, returning `true` if the scroll offset changed.
+    ///
+    /// The velocity is kept so scrolling can carry on while the cursor is
+    /// still.
+    fn auto_scroll_cells(
+        &mut self,
+        layout: layout::Layout<'_>,
+        viewport: Size,
+        position: Point,
+    ) -> bool {
+        let mut children = layout.children();
+        let numbering = children.next().map(|numbering| numbering.bounds().width);
+        let headers = children.next().map(|headers| headers.bounds().height);
+
+        let top_left = layout.position()
+            + Vector::new(numbering.unwrap_or_default(), headers.unwrap_or_default());
+        let bounds = Rectangle::new(top_left, viewport);
+
+        let velocity = |position: f32, start: f32, end: f32| {
+            if position < start + Self::AUTO_SCROLL_EDGE {
+                start + Self::AUTO_SCROLL_EDGE - position
+            } else if position > end - Self::AUTO_SCROLL_EDGE {
+                end - Self::AUTO_SCROLL_EDGE - position
+            } else {
+                0.0
+            }
+        };
 
-        let total_size = Size::new(offset, page.size().max(input.size().max(go.size())).height);
+        self.auto_scroll = Vector::new(
+            velocity(position.x, bounds.x, bounds.x + bounds.width),
+            velocity(position.y, bounds.y, bounds.y + bounds.height),
+        ) * Self::AUTO_SCROLL_MULT;
 
-        Node::with_children(total_size, vec![page, input, go])
+        self.apply_auto_scroll(viewport)
     }
 
-    fn layout_status<Raw: RawTable, Message, Theme: Catalog>(
-        &mut self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        font: Renderer::Font,
-        max_width: f32,
-    ) -> Node {
-        if table.raw.is_empty() {
-            return Node::default();
+    /// Scrolls the cells by the current auto scroll velocity, returning `true`
+    /// if the scroll offset changed.
+    fn apply_auto_scroll(&mut self, viewport: Size) -> bool {
+        if self.auto_scroll == Vector::ZERO {
+            return false;
         }
 
-        let bounds = Size::new(max_width, f32::INFINITY);
-        let (cell, value) = &mut self.status;
-        let value = match table.status.as_ref() {
-            Some(status) => status,
-            None => value,
-        };
-
-        cell.update(super::text::<Renderer>(
-            value,
-            bounds,
-            font,
-            Horizontal::Center,
-            self.page_size,
-        ));
+        let previous = self.scroll_offset;
+        self.scroll_cells(viewport, self.auto_scroll);
 
-        let size = cell.min_bounds().expand(self.pages_padding);
+        if previous == self.scroll_offset {
+            self.auto_scroll = Vector::ZERO;
+            return false;
+        }
 
-        Node::new(size)
+        true
     }
 
-    pub fn layout<Raw: RawTable, Message, Theme: Catalog>(
+    fn layout_cells<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
         renderer: &Renderer,
-        limits: Limits,
+        font: Renderer::Font,
+        max_width: f32,
     ) -> Node {
-        let font = table.font.unwrap_or_else(|| renderer.default_font());
-        let text_size = table.text_size.unwrap_or_else(|| renderer.default_size());
-        let spacing = if table.raw.is_empty() {
-            0.0
+        let header_font = table.header_font.unwrap_or_else(|| renderer.default_font());
+        let numbering_font = table
+            .numbering_font
+            .unwrap_or_else(|| renderer.default_font());
+        let padding = table.cell_padding;
+        let size = table.text_size.unwrap_or_else(|| renderer.default_size());
+        let header_size = table.header_text_size.unwrap_or(size);
+        let numbering_size = table.numbering_text_size.unwrap_or(size);
+
+        let gap = self.cell_gap;
+        // Adds numbering column
+        let dimensions = (table.rows, table.cols + 1);
+        // Adds headers row
+        let page_limit = table.page_limit + 1;
+
+        self.sort_width = if table.sortable {
+            let mut width = 0.0f32;
+
+            for (arrow, glyph) in self.sort_arrows.iter_mut().zip(SORT_ARROWS) {
+                arrow.update(super::text::<Renderer>(
+                    glyph,
+                    self.max_cell,
+                    header_font,
+                    Horizontal::Center,
+                    header_size,
+                    self.shaping,
+                    self.line_height,
+                ));
+                width = width.max(arrow.min_width());
+            }
+
+            width + gap
         } else {
-            table.spacing
+            0.0
         };
 
-        self.pre_layout(table, font, text_size);
+        let numbering_max = dimensions.0;
+        let numbering_max = Cell::<Renderer>::new(super::text::<Renderer>(
+            &numbering_max.to_string(),
+            self.max_cell,
+            numbering_font,
+            Horizontal::Right,
+            numbering_size,
+            self.shaping,
+            self.line_height,
+        ))
+        .min_bounds()
+        .expand(padding);
 
-        let padding = table.padding;
+        let total = dimensions.1 * page_limit;
+        let mut knds_height = vec![];
+        // Without numbering, its column is skipped entirely
+        let first = if table.numbering { 0 } else { page_limit };
+        let mut curr = first;
+        self.placeholders.clear();
+        self.matches.clear();
+
+        if !table.numbering {
+            self.min_widths[0] = 0.0;
+        }
 
-        let content_limits = limits
-            .width(table.width)
-            .height(table.height)
-            .shrink(table.padding);
+        // Columns only keep their left over width until the next fill stage
+        for (column, width) in self.filled_widths.drain() {
+            if let Some(min_width) = self.min_widths.get_mut(column) {
+                *min_width -= width;
+            }
+        }
 
-        let mut pagination = if table.multiple_pages() {
-            self.layout_pagination(table, font)
-        } else {
-            Node::default()
+        // Only the columns in view are shaped, the others keep the sizes they
+        // were last measured at
+        let visible = self.visible_columns(table, max_width);
+        let edited = match self.editing {
+            Some(Editing::Cell {
+                index, is_header, ..
+            }) => Some(if is_header {
+                index
+            } else {
+                index / table.page_limit
+            }),
+            _ => None,
         };
-        let pagination_size = pagination.size();
-
-        let mut goto = if table.multiple_pages() {
-            self.layout_goto(table, font)
-        } else {
-            Node::default()
+        let is_shown = |column: usize| {
+            !table.is_hidden(column)
+                && (column < table.frozen_columns
+                    || visible.contains(&column)
+                    || edited == Some(column))
         };
-        let goto_size = goto.size();
 
-        let actions = Size::new(
-            pagination_size.width + spacing + goto_size.width,
-            pagination_size.height.max(goto_size.height),
-        );
+        // Prep stage. Fill the paragraphs, register the dimensions
+        while curr < total {
+            let row = curr % page_limit;
+            let column = curr / page_limit;
 
-        let actions_spacing = if table.multiple_pages() { spacing } else { 0.0 };
+            let size = if column != 0 && !is_shown(column - 1) {
+                if row == 0 {
+                    let height = if table.show_column_kinds {
+                        self.headers[column - 1].1.min_bounds().height
+                    } else {
+                        0.0
+                    };
+                    knds_height.push(height);
+                }
 
-        let mut status = self.layout_status(table, font, content_limits.max().width);
-        let status_size = status.size();
-        status.translate_mut(Vector::new(
-            padding.left,
-            padding.top + actions.height + actions_spacing,
-        ));
+                Size::ZERO
+            } else if column != 0 {
+                let column = column - 1;
+                let kind = table.raw.column_kind(column).expect("Missing table column");
+                let horizontal = if row != 0 && table.is_checkbox(column) {
+                    Horizontal::Center
+                } else {
+                    table.cell_alignment(column, &kind)
+                };
 
-        let cells = self
-            .layout_cells(table, renderer, font)
-            .translate(Vector::new(
-                padding.left,
-                padding.top + actions.height + actions_spacing + status_size.height + spacing,
-            ));
-        let cells_size = cells.size();
+                if row == 0 {
+                    let (header, knd) = &mut self.headers[column];
+                    let label = match self.editing.as_ref() {
+                        Some(Editing::Cell {
+                            index,
+                            value,
+                            is_header: true,
+                            ..
+                        }) if *index == column => value,
+                        _ => &table.raw.column_header(column).unwrap_or_default(),
+                    };
+                    let text = super::text::<Renderer>(
+                        label,
+                        self.max_cell,
+                        header_font,
+                        Horizontal::Center,
+                        header_size,
+                        self.shaping,
+                        self.line_height,
+                    );
+                    header.update(text);
+                    let header = header.min_bounds() + Size::new(self.sort_width, 0.0);
+
+                    if table.show_column_kinds {
+                        let kind = kind.to_string();
+                        let text = super::text::<Renderer>(
+                            &kind,
+                            self.max_cell,
+                            header_font,
+                            Horizontal::Center,
+                            header_size * Self::KIND_MULT,
+                            self.shaping,
+                            self.line_height,
+                        );
+                        knd.update(text);
 
-        let total_size = Size::new(
-            actions.width.max(cells_size.width),
-            actions.height + actions_spacing + status_size.height + spacing + cells_size.height,
-        )
-        .expand(padding);
+                        let knd = knd.min_bounds();
 
-        let size = limits.resolve(table.width, table.height, total_size);
+                        knds_height.push(knd.height);
+                        Size::new(header.width.max(knd.width), header.height + knd.height)
+                    } else {
+                        knds_height.push(0.0);
+                        header
+                    }
+                } else {
+                    let row = row - 1;
+                    let idx = (column * table.page_limit) + (row % table.page_limit);
+                    let paragraph = &mut self.cells[idx];
+                    let row = row + (self.page * (page_limit - 1));
 
-        let sum = pagination_size.width + spacing + goto_size.width;
-        let diff = (size.width - sum) * 0.5;
+                    let displayed;
+                    let (value, is_editing) = match self.editing.as_ref() {
+                        Some(Editing::Cell {
+                            index,
+                            value,
+                            is_header: false,
+                            ..
+                        }) if *index == idx => {
+                            self.shaped.remove(&idx);
+                            (value.as_str(), true)
+                        }
+                        _ => {
+                            let formatted = table.formatted_cell(row, column);
 
-        let mut offset_x = diff;
-        let offset_y = padding.top;
+                            if formatted.is_none() && row < table.rows {
+                                self.placeholders.insert(idx);
+                            }
 
-        pagination.translate_mut([offset_x, offset_y]);
-        offset_x += pagination_size.width + spacing;
-        goto.translate_mut([offset_x, offset_y]);
+                            displayed = formatted.unwrap_or(Cow::Borrowed(&table.none_placeholder));
 
-        let children = vec![cells, status, pagination, goto];
+                            if row < table.rows && table.is_match(&displayed) {
+                                self.matches.insert(idx);
+                            }
 
-        Node::with_children(size, children)
-    }
+                            (&*displayed, false)
+                        }
+                    };
 
-    fn draw_pages(
-        &self,
-        renderer: &mut Renderer,
-        layout: layout::Layout<'_>,
-        style: Style,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
-    ) {
-        for ((cell, content), layout) in self.paginations.iter().zip(layout.children()) {
-            let bounds = layout.bounds();
-            let (background, text_color) = if (self.page + 1).to_string() == *content {
-                (style.selected_page_background, style.selected_page_text)
-            } else if cursor.is_over(bounds) {
-                (style.hovered_page_background, style.hovered_page_text)
+                    // Truncations are only undone by the ellipsis stage, and
+                    // wraps by the wrap stage
+                    let shaped = self.shaped.get(&idx).filter(|shaped| {
+                        shaped.value == *value
+                            && paragraph.horizontal_alignment() == horizontal
+                            && (shaped.truncated_to.is_none()
+                                || table.overflow == Overflow::Ellipsis)
+                            && (shaped.wrapped_to.is_none() || table.overflow == Overflow::Grow)
+                    });
+
+                    if let Some(shaped) = shaped {
+                        shaped.min_bounds
+                    } else {
+                        let text = super::text::<Renderer>(
+                            value,
+                            self.max_cell,
+                            font,
+                            horizontal,
+                            size,
+                            self.shaping,
+                            self.line_height,
+                        );
+                        paragraph.update(text);
+                        let min_bounds = paragraph.min_bounds();
+
+                        if !is_editing {
+                            self.shaped.insert(
+                                idx,
+                                Shaped {
+                                    value: value.to_owned(),
+                                    min_bounds,
+                                    truncated_to: None,
+                                    wrapped_to: None,
+                                },
+                            );
+                        }
+
+                        min_bounds
+                    }
+                }
+            } else if row != 0 {
+                let paragraph = &mut self.numbering[row];
+                let row = (row - 1) + (table.page_limit * self.page);
+
+                paragraph.update(super::text::<Renderer>(
+                    &table.raw.row_number(row).to_string(),
+                    self.max_cell,
+                    numbering_font,
+                    Horizontal::Right,
+                    numbering_size,
+                    self.shaping,
+                    self.line_height,
+                ));
+
+                paragraph.min_bounds()
             } else {
-                (style.page_background, style.page_text)
+                Size::ZERO
+            }
+            .expand(padding);
+
+            let height = match self.manual_heights.get(&self.height_key(row)) {
+                Some(height) => *height,
+                None => self.min_heights[row].max(size.height),
             };
+            self.min_heights[row] = height;
 
-            if let Some(clipped_viewport) = bounds.intersection(viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds: clipped_viewport,
-                        border: style.page_border,
-                        ..Default::default()
-                    },
-                    background,
-                );
+            let width = if column == 0 {
+                numbering_max.width
+            } else if let Some(width) = self.manual_widths.get(&column) {
+                *width
+            } else {
+                self.min_widths[column].max(size.width.min(table.max_column_width))
+            };
+            self.min_widths[column] = width;
 
-                draw(
-                    renderer,
-                    text_color,
-                    layout,
-                    cell.raw(),
-                    self.pages_padding,
-                    &clipped_viewport,
-                )
+            curr += 1;
+        }
+
+        // Hidden columns take no room at all
+        for column in 0..table.cols {
+            if table.is_hidden(column) {
+                self.min_widths[column + 1] = 0.0;
             }
         }
-    }
 
-    fn draw_pagination<Raw: RawTable, Message, Theme: Catalog>(
-        &self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &mut Renderer,
-        layout: layout::Layout<'_>,
-        style: Style,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
-    ) {
-        let mut children = layout.children();
-        {
-            let back = children.next().expect("Missing paginations: Back");
+        // Fill stage. Share the width left over by the columns among the
+        // flexible ones
+        if table.fill_columns && max_width.is_finite() {
+            let used = self
+                .min_widths
+                .iter()
+                .enumerate()
+                .filter(|(column, _)| match column {
+                    0 => table.numbering,
+                    column => !table.is_hidden(column - 1),
+                })
+                .map(|(_, width)| width + gap)
+                .sum::<f32>();
+            let flexible = (1..self.min_widths.len())
+                .filter(|column| {
+                    !self.manual_widths.contains_key(column) && !table.is_hidden(column - 1)
+                })
+                .collect::<Vec<usize>>();
+            let left_over = max_width - used;
+
+            if left_over > 0.0 && !flexible.is_empty() {
+                let width = left_over / flexible.len() as f32;
+
+                for column in flexible {
+                    self.min_widths[column] += width;
+                    self.filled_widths.insert(column, width);
+                }
+            }
+        }
 
-            let (background, text_color) = if self.page == 0 {
-                (
-                    style.pagination_background.scale_alpha(0.5),
-                    style.pagination_text.scale_alpha(0.5),
-                )
-            } else if cursor.is_over(back.bounds()) {
-                (
-                    style.hovered_pagination_background,
-                    style.hovered_pagination_text,
-                )
-            } else {
-                (style.pagination_background, style.pagination_text)
-            };
+        // Wrap stage. Lay the cells out against the widths of their columns
+        if table.overflow == Overflow::Grow {
+            let page_start = self.page * self.page_limit;
 
-            if let Some(bounds) = back.bounds().intersection(viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds,
-                        border: style.pagination_border,
-                        ..Default::default()
-                    },
-                    background,
-                );
-                draw(
-                    renderer,
-                    text_color,
-                    back,
-                    self.page_back.raw(),
-                    self.pages_padding,
-                    viewport,
-                );
-            }
-        };
+            for (idx, paragraph) in self.cells.iter_mut().enumerate() {
+                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                if !is_shown(column) {
+                    continue;
+                }
 
-        let pages = children.next().expect("Missing paginations: Pages");
+                let bounds = table
+                    .wrap_bounds(self.min_widths[column + 1], size)
+                    .unwrap_or(self.max_cell);
 
-        self.draw_pages(renderer, pages, style, cursor, viewport);
+                let is_editing = matches!(
+                    self.editing.as_ref(),
+                    Some(Editing::Cell {
+                        index,
+                        is_header: false,
+                        ..
+                    }) if *index == idx
+                );
+                let mut shaped = self.shaped.get_mut(&idx).filter(|_| !is_editing);
 
-        {
-            let next = children.next().expect("Missing paginations: Next");
+                // Cells already wrapped to these bounds are left as they are
+                let is_wrapped = shaped
+                    .as_ref()
+                    .is_some_and(|shaped| shaped.wrapped_to == Some(bounds));
 
-            let (background, text_color) = if self.page == table.pages_end() {
-                (
-                    style.pagination_background.scale_alpha(0.5),
-                    style.pagination_text.scale_alpha(0.5),
-                )
-            } else if cursor.is_over(next.bounds()) {
-                (
-                    style.hovered_pagination_background,
-                    style.hovered_pagination_text,
-                )
-            } else {
-                (style.pagination_background, style.pagination_text)
-            };
+                if !is_wrapped {
+                    let displayed;
+                    let value = match (self.editing.as_ref(), shaped.as_ref()) {
+                        (
+                            Some(Editing::Cell {
+                                index,
+                                value,
+                                is_header: false,
+                                ..
+                            }),
+                            _,
+                        ) if *index == idx => value.as_str(),
+                        (_, Some(shaped)) => &shaped.value,
+                        _ => {
+                            displayed =
+                                table.display_cell(row + (self.page * table.page_limit), column);
+                            &displayed
+                        }
+                    };
 
-            if let Some(bounds) = next.bounds().intersection(viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
+                    let horizontal = paragraph.horizontal_alignment();
+                    paragraph.update(super::text::<Renderer>(
+                        value,
                         bounds,
-                        border: style.pagination_border,
-                        ..Default::default()
-                    },
-                    background,
-                );
-                draw(
-                    renderer,
-                    text_color,
-                    next,
-                    self.page_next.raw(),
-                    self.pages_padding,
-                    viewport,
-                );
-            }
-        }
-    }
+                        font,
+                        horizontal,
+                        size,
+                        self.shaping,
+                        self.line_height,
+                    ));
 
-    fn draw_goto(
-        &self,
-        renderer: &mut Renderer,
-        layout: layout::Layout<'_>,
-        style: Style,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
-    ) {
-        let mut children = layout.children();
-        {
-            let page = children.next().expect("Widget draw: Missing Goto Page");
+                    if let Some(shaped) = shaped.as_mut() {
+                        shaped.wrapped_to = Some(bounds);
+                    }
+                }
 
-            if let Some(bounds) = page.bounds().intersection(viewport) {
-                draw(
-                    renderer,
-                    style.goto_page_text,
-                    page,
-                    self.goto_page.raw(),
-                    self.pages_padding,
-                    &bounds,
-                );
+                let row = row + 1;
+                if !self.manual_heights.contains_key(&(page_start + row)) {
+                    let height = paragraph.min_bounds().expand(padding).height;
+                    self.min_heights[row] = self.min_heights[row].max(height);
+                }
             }
         }
 
-        {
-            let input = children.next().expect("Widget draw: Missing Goto Input");
+        // Ellipsis stage. Truncate the cells overflowing their columns
+        self.truncated.clear();
+        if table.overflow == Overflow::Ellipsis {
+            self.cell_ellipsis.update(super::text::<Renderer>(
+                OVERFLOW_ELLIPSIS,
+                self.max_cell,
+                font,
+                Horizontal::Left,
+                size,
+                self.shaping,
+                self.line_height,
+            ));
+            let ellipsis = self.cell_ellipsis.min_width();
 
-            if let Some(bounds) = input.bounds().intersection(viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds,
-                        ..Default::default()
-                    },
-                    style.goto_input_background,
-                );
-                draw(
-                    renderer,
-                    style.goto_input_text,
-                    input,
-                    self.goto_input.0.raw(),
-                    self.pages_padding,
-                    viewport,
-                );
-            }
-        }
+            for (idx, paragraph) in self.cells.iter_mut().enumerate() {
+                let column = idx / table.page_limit;
+                if !is_shown(column) {
+                    continue;
+                }
 
-        {
-            let go = children.next().expect("Widget draw: Missing Goto Go");
+                let available = self.min_widths[column + 1] - padding.horizontal();
 
-            if let Some(bounds) = go.bounds().intersection(viewport) {
-                let (background, text_color) = if cursor.is_over(go.bounds()) {
-                    (style.hovered_goto_background, style.hovered_goto_text)
-                } else {
-                    (style.goto_background, style.goto_text)
+                // Only the cells being edited are left unshaped
+                let Some(shaped) = self.shaped.get_mut(&idx) else {
+                    continue;
                 };
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds,
-                        border: style.goto_border,
-                        ..Default::default()
-                    },
-                    background,
-                );
-                draw(
-                    renderer,
-                    text_color,
-                    go,
-                    self.goto_go.raw(),
-                    self.pages_padding,
-                    viewport,
-                );
-            }
-        }
-    }
 
-    fn draw_status(
-        &self,
-        renderer: &mut Renderer,
-        layout: layout::Layout<'_>,
-        style: Style,
-        viewport: &Rectangle,
-    ) {
-        if let Some(bounds) = layout.bounds().intersection(viewport) {
-            <Renderer as advanced::Renderer>::fill_quad(
-                renderer,
-                Quad {
-                    bounds,
-                    ..Default::default()
-                },
-                style.status_background,
-            );
+                let fits = shaped.min_bounds.width <= available;
+                if shaped
+                    .truncated_to
+                    .is_some_and(|width| fits || width != available)
+                {
+                    let horizontal = paragraph.horizontal_alignment();
+                    paragraph.update(super::text::<Renderer>(
+                        &shaped.value,
+                        self.max_cell,
+                        font,
+                        horizontal,
+                        size,
+                        self.shaping,
+                        self.line_height,
+                    ));
+                    shaped.truncated_to = None;
+                }
 
-            draw(
-                renderer,
-                style.status_text,
-                layout,
-                self.status.0.raw(),
-                self.pages_padding,
-                viewport,
-            )
-        }
-    }
+                if fits {
+                    continue;
+                }
 
-    fn draw_cells<Raw: RawTable, Message, Theme: Catalog>(
-        &self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &mut Renderer,
-        layout: layout::Layout<'_>,
-        style: Style,
-        viewport: Rectangle,
-        padding: Padding,
-    ) {
-        let og_viewport = viewport;
-        if let Some(clipped) = layout.bounds().intersection(&viewport) {
-            <Renderer as advanced::Renderer>::fill_quad(
-                renderer,
-                Quad {
-                    bounds: clipped,
-                    ..Default::default()
-                },
-                style.cell_border,
-            );
-        }
+                if shaped.truncated_to.is_none() {
+                    Self::truncate(
+                        paragraph,
+                        &shaped.value,
+                        available,
+                        ellipsis,
+                        self.max_cell,
+                        font,
+                        size,
+                        self.shaping,
+                        self.line_height,
+                    );
+                    shaped.truncated_to = Some(available);
+                }
 
-        let mut editing: Option<Rectangle> = None;
+                self.truncated.insert(idx);
+            }
+        }
 
-        let mut children = layout.children();
-        let numbering = children
-            .next()
-            .expect("Widget draw: Missing numbering cells");
-        let headers = children.next().expect("Widget draw: Missing header cells");
-        let cells = children.next().expect("Widget draw: Missing cells layout");
+        if self
+            .overflow_hover
+            .as_ref()
+            .is_some_and(|(idx, _)| !self.truncated.contains(idx))
+        {
+            self.overflow_hover = None;
+        }
 
-        let mut top_left: Option<Size> = None;
+        curr = first;
+
+        self.frozen_width = (0..table.frozen_columns.min(table.cols))
+            .map(|column| self.column_span(table, column))
+            .sum();
+
+        // Group stage. Shape the labels of the band above the headers
+        self.groups
+            .resize_with(table.column_groups.len(), Cell::<Renderer>::default);
+        let mut band: f32 = 0.0;
+        for (paragraph, (label, _)) in self.groups.iter_mut().zip(&table.column_groups) {
+            paragraph.update(super::text::<Renderer>(
+                label,
+                self.max_cell,
+                header_font,
+                Horizontal::Center,
+                header_size,
+                self.shaping,
+                self.line_height,
+            ));
+            band = band.max(paragraph.min_bounds().expand(padding).height);
+        }
+        self.groups_height = if self.groups.is_empty() {
+            0.0
+        } else {
+            band + gap
+        };
 
-        let numbering_viewport = {
-            let moved = viewport + Vector::new(0.0, headers.bounds().height);
+        let mut offset_width = 0.0;
+        let mut offset_height = 0.0;
+        let mut headers_x = 0.0;
+        let mut numbering_y = 0.0;
+        let mut children = vec![];
+        let mut headers = vec![];
+        let mut numbering = vec![];
 
-            let size = Size::new(moved.width, moved.height - headers.bounds().height);
+        // Create the layout nodes
+        while curr < total {
+            let row = curr % page_limit;
+            let column = curr / page_limit;
 
-            Rectangle::new(moved.position(), size)
-        };
+            // Hidden columns are laid out without their gaps
+            let gap_x = if column != 0 && table.is_hidden(column - 1) {
+                0.0
+            } else {
+                gap
+            };
 
-        for (idx, (number, layout)) in self.numbering.iter().zip(numbering.children()).enumerate() {
-            let bounds = layout.bounds();
-
-            if let Some(clipped_viewport) = bounds.intersection(&numbering_viewport) {
-                let child = layout
-                    .children()
-                    .next()
-                    .expect("Table draw: Resize node missing child layout");
+            if column != 0 {
+                if row == 0 {
+                    let height = self.min_heights[row];
+                    let width = self.min_widths[column];
+                    let knd_height = knds_height[column - 1];
+                    let label = Size::new(
+                        (width - padding.horizontal() - self.sort_width).max(0.0),
+                        height - padding.vertical() - knd_height,
+                    );
+                    let label_node = Node::new(label).translate([padding.left, padding.top]);
 
-                top_left = Some(Size::new(child.bounds().width, 0.0));
+                    let children = if table.show_column_kinds {
+                        let knd = Size::new((width - padding.horizontal()).max(0.0), knd_height);
+                        let knd =
+                            Node::new(knd).translate([padding.left, label.height + padding.top]);
 
-                if let Some(clipped_viewport) = child.bounds().intersection(&clipped_viewport) {
-                    let (background, text_color) = if idx % 2 == 1 {
-                        (
-                            style.alternating_backgrounds.1,
-                            style.alternating_text_color.1,
-                        )
+                        vec![label_node, knd]
                     } else {
-                        (
-                            style.alternating_backgrounds.0,
-                            style.alternating_text_color.0,
-                        )
+                        vec![label_node]
                     };
 
-                    <Renderer as advanced::Renderer>::fill_quad(
-                        renderer,
-                        Quad {
-                            bounds: clipped_viewport,
-                            ..Default::default()
-                        },
-                        background,
-                    );
+                    let size = Size::new(width, height);
+                    let node = Node::with_children(size, children);
 
-                    draw(
-                        renderer,
-                        text_color,
-                        child,
-                        number.raw(),
-                        padding,
-                        &clipped_viewport,
-                    )
+                    let size = size + Size::from([gap_x, gap]);
+                    let node = Node::with_children(size, vec![node]).translate([
+                        headers_x + self.frozen_shift(table, column - 1),
+                        self.groups_height,
+                    ]);
+
+                    headers_x += size.width;
+                    headers.push(node);
+                } else {
+                    let size = Size::new(self.min_widths[column], self.min_heights[row]);
+                    let node = Node::new(size);
+
+                    let size = size + Size::from([gap_x, gap]);
+                    let node = Node::with_children(size, vec![node]).translate([
+                        offset_width + self.frozen_shift(table, column - 1),
+                        offset_height,
+                    ]);
+
+                    if (curr + 1) / page_limit == column {
+                        offset_height += size.height;
+                    } else {
+                        offset_height = 0.0;
+                        offset_width += size.width;
+                    }
+
+                    children.push(node);
                 }
+            } else {
+                // The corner also spans the column groups band
+                let height = match row {
+                    0 => self.min_heights[row] + self.groups_height,
+                    _ => self.min_heights[row],
+                };
+                let size = Size::new(self.min_widths[column], height);
+                let node = Node::new(size);
+
+                let size = size + Size::from([gap, gap]);
+                let node = Node::with_children(size, vec![node]).translate([0.0, numbering_y]);
+                numbering_y += size.height;
+                numbering.push(node);
             }
+
+            curr += 1;
         }
 
-        let viewport = {
-            let moved = viewport + Vector::new(numbering.bounds().width, 0.0);
+        let numbering = {
+            let width = numbering
+                .first()
+                .map(|node| node.size().width)
+                .unwrap_or_default();
+            let height = numbering_y;
 
-            let size = Size::new(moved.width - numbering.bounds().width, moved.height);
+            let size = Size::new(width, height);
 
-            Rectangle::new(moved.position(), size)
+            Node::with_children(size, numbering).translate([0.0, self.scroll_offset.y])
         };
 
-        let header_viewport = viewport;
-
-        for (idx, ((header, kind), layout)) in
-            self.headers.iter().zip(headers.children()).enumerate()
-        {
-            let pair = layout
-                .children()
-                .next()
-                .expect("Table draw: Resize node missing pair layout");
-
-            let mut children = pair.children();
-            let label = children
-                .next()
-                .expect("Table draw: Pair node missing label layout");
-            let knd = children
-                .next()
-                .expect("Table draw: Pair node missing kind layout");
-
-            let is_selected = self
-                .selection
-                .as_ref()
-                .map(|selection| selection.header(idx))
+        let headers = {
+            let width = headers_x;
+            let height = headers
+                .first()
+                .map(|node| node.size().height)
                 .unwrap_or_default();
 
-            top_left = top_left.map(|size| Size::new(size.width, pair.bounds().height));
+            let size = Size::new(width, height + self.groups_height);
+            Node::with_children(size, headers)
+                .translate([numbering.size().width + self.scroll_offset.x, 0.])
+        };
 
-            if is_selected {
-                let bounds = pair.bounds().expand([CELL_GAP, CELL_GAP]);
-                if let Some(clipped_viewport) = bounds.intersection(&viewport) {
-                    <Renderer as advanced::Renderer>::fill_quad(
-                        renderer,
-                        Quad {
-                            bounds: clipped_viewport,
-                            ..Default::default()
-                        },
-                        style.selected_header_border,
-                    );
-                }
-            }
+        let groups = {
+            let last = table.cols.saturating_sub(1);
+            let groups = table
+                .column_groups
+                .iter()
+                .map(|(_, columns)| {
+                    let start = *columns.start();
+                    let x = (0..start)
+                        .map(|column| self.column_span(table, column))
+                        .sum::<f32>();
+                    let width = (start..=(*columns.end()).min(last))
+                        .map(|column| self.column_span(table, column))
+                        .sum::<f32>();
+
+                    let size = Size::new(width, self.groups_height);
+                    let group = Node::new(size - Size::new(gap, gap).min(size));
+
+                    Node::with_children(size, vec![group])
+                        .translate([x + self.frozen_shift(table, start), 0.0])
+                })
+                .collect();
+
+            Node::with_children(Size::new(headers_x, self.groups_height), groups)
+                .translate([numbering.size().width + self.scroll_offset.x, 0.])
+        };
 
-            if let Some(clipped_viewport) = pair.bounds().intersection(&viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds: clipped_viewport,
-                        ..Default::default()
-                    },
-                    style.header_background,
-                );
-            }
+        let total_height = self
+            .min_heights
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx != 0)
+            .fold(0.0, |acc, (_, curr)| acc + curr + gap);
 
-            if let Some(label_viewport) = label.bounds().intersection(&viewport) {
-                draw(
-                    renderer,
-                    style.header_text,
-                    label,
-                    header.raw(),
-                    Padding::from(0),
-                    &label_viewport,
-                )
-            }
+        let total_width = (0..table.cols)
+            .map(|column| self.column_span(table, column))
+            .sum::<f32>();
 
-            if let Some(kind_viewport) = knd.bounds().intersection(&viewport) {
-                draw(
-                    renderer,
-                    style.header_type,
-                    knd,
-                    kind.raw(),
-                    Padding::from(0),
-                    &kind_viewport,
-                )
-            }
+        let size = Size::new(total_width, total_height);
+        self.cells_dim = size;
 
-            if let Some(Editing::Cell {
-                index,
-                is_header: true,
-                ..
-            }) = &self.editing
-            {
-                if idx == *index {
-                    editing.replace(label.bounds());
-                }
-            }
-        }
+        let cells = Node::with_children(size, children).translate(Vector::new(
+            self.scroll_offset.x + numbering.size().width,
+            self.scroll_offset.y + headers.size().height,
+        ));
 
-        let viewport = {
-            let moved = viewport + Vector::new(0.0, headers.bounds().height);
+        let size = {
+            let width = numbering.size().width + headers.size().width.max(cells.size().width);
+            let height = (headers.size().height + cells.size().height).max(numbering.size().height);
 
-            let size = Size::new(moved.width, moved.height - headers.bounds().height);
+            Size::new(width, height)
+        };
+        Node::with_children(size, vec![numbering, headers, cells, groups])
+    }
 
-            Rectangle::new(moved.position(), size)
+    /// Lays out the footer row along the columns laid out by `layout_cells`.
+    fn layout_footer<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        font: Renderer::Font,
+    ) -> Node {
+        let Some(footer) = table.footer.as_ref() else {
+            self.footer.clear();
+            self.footer_height = 0.0;
+            return Node::default();
         };
-        let cell_viewport = viewport;
 
-        for (idx, (cell, layout)) in self.cells.iter().zip(cells.children()).enumerate() {
-            let bounds = layout.bounds();
-            let child = layout
-                .children()
-                .next()
-                .expect("Table draw: Resize node missing child layout");
+        let padding = table.cell_padding;
+        let size = table.text_size.unwrap_or_else(|| renderer.default_size());
+        let gap = self.cell_gap;
+
+        self.footer
+            .resize_with(table.cols, Cell::<Renderer>::default);
+
+        let mut height: f32 = 0.0;
+        for (column, paragraph) in self.footer.iter_mut().enumerate() {
+            let value = footer(column).unwrap_or_default();
+            let horizontal = table
+                .raw
+                .column_kind(column)
+                .map_or(Horizontal::Left, |kind| table.cell_alignment(column, &kind));
+
+            paragraph.update(super::text::<Renderer>(
+                &value,
+                self.max_cell,
+                font,
+                horizontal,
+                size,
+                self.shaping,
+                self.line_height,
+            ));
 
-            if let Some(clipped_viewport) = bounds.intersection(&viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds: clipped_viewport,
-                        ..Default::default()
-                    },
-                    Background::Color(Color::TRANSPARENT),
-                );
+            height = height.max(paragraph.min_bounds().expand(padding).height);
+        }
 
-                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
-                let row = row + (self.page * table.page_limit);
+        let numbering = if table.numbering {
+            self.min_widths[0] + gap
+        } else {
+            0.0
+        };
 
-                let is_in_motion = self
-                    .motion
-                    .as_ref()
-                    .map(|motion| motion.contains(row, column))
-                    .unwrap_or_default();
+        let mut x = numbering + self.scroll_offset.x;
+        let children = self
+            .min_widths
+            .iter()
+            .skip(1)
+            .enumerate()
+            .map(|(column, width)| {
+                let size = Size::new(*width, height);
+                let node = Node::new(size);
 
-                let (selection, is_selected) = self
-                    .selection
-                    .as_ref()
-                    .map(|selection| {
-                        (
-                            selection.border(row, column),
-                            selection.contains(row, column),
-                        )
-                    })
-                    .unwrap_or_default();
+                let gap_x = if table.is_hidden(column) { 0.0 } else { gap };
+                let size = size + Size::from([gap_x, gap]);
+                let node = Node::with_children(size, vec![node])
+                    .translate([x + self.frozen_shift(table, column), 0.0]);
+                x += size.width;
 
-                let selection = {
-                    let mut padding = Padding::ZERO;
+                node
+            })
+            .collect();
 
-                    if (selection & 1) == 1 {
-                        padding = padding.left(CELL_GAP);
-                    }
+        self.footer_height = height + gap;
 
-                    if ((selection >> 1) & 1) == 1 {
-                        padding = padding.top(CELL_GAP);
-                    }
+        Node::with_children(
+            Size::new(numbering + self.cells_dim.width, self.footer_height),
+            children,
+        )
+    }
 
-                    if ((selection >> 2) & 1) == 1 {
-                        padding = padding.right(CELL_GAP);
-                    }
+    fn layout_pagination<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        font: Renderer::Font,
+        compact: bool,
+    ) -> Node {
+        self.compact_pagination = compact;
 
-                    if ((selection >> 3) & 1) == 1 {
-                        padding = padding.bottom(CELL_GAP);
-                    }
+        if table.raw.is_empty() {
+            return Node::with_children(Size::ZERO, vec![Node::default(); 3]);
+        }
 
-                    padding
-                };
+        let gap = self.pages_gap;
+        let pages_end = table.pages_end() + 1;
+        let current_page = self.page + 1;
 
-                if let Some(selection_viewport) =
-                    child.bounds().expand(selection).intersection(&viewport)
-                {
-                    <Renderer as advanced::Renderer>::fill_quad(
-                        renderer,
-                        Quad {
-                            bounds: selection_viewport,
-                            border: iced::Border::default().rounded(2.0),
-                            ..Default::default()
-                        },
-                        style.selected_cell_border,
-                    );
-                }
+        let pages = if compact {
+            vec![format!("{current_page} / {pages_end}")]
+        } else if pages_end <= table.pagination_limit {
+            (1..=pages_end)
+                .map(|num| num.to_string())
+                .collect::<Vec<String>>()
+        } else {
+            gen_pagination(
+                1,
+                pages_end as isize,
+                current_page as isize,
+                table.pagination_limit,
+            )
+        };
 
-                if let Some(clipped_viewport) = child.bounds().intersection(&clipped_viewport) {
-                    let row = idx % table.page_limit;
+        let mut min_bounds = Size::ZERO;
+        let count = pages.len().min(self.paginations.len());
 
-                    let (cell_background, text_color) = if row % 2 == 0 {
-                        (
-                            style.alternating_backgrounds.1,
-                            style.alternating_text_color.1,
-                        )
-                    } else {
-                        (
-                            style.alternating_backgrounds.0,
-                            style.alternating_text_color.0,
-                        )
-                    };
+        // Update paragraphs, register min width
+        for (page, (cell, content)) in pages.into_iter().zip(self.paginations.iter_mut()) {
+            let text = super::text::<Renderer>(
+                &page,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                self.page_size,
+                self.shaping,
+                self.line_height,
+            );
+            cell.update(text);
+            *content = page;
 
-                    <Renderer as advanced::Renderer>::fill_quad(
-                        renderer,
-                        Quad {
-                            bounds: clipped_viewport,
-                            ..Default::default()
-                        },
-                        cell_background,
-                    );
+            let size = cell.min_bounds().expand(self.pages_padding);
 
-                    if is_selected && self.editing.is_none() {
-                        <Renderer as advanced::Renderer>::fill_quad(
-                            renderer,
-                            Quad {
-                                bounds: clipped_viewport,
-                                ..Default::default()
-                            },
-                            style.selected_cell_background,
-                        );
-                    }
+            min_bounds = min_bounds.max(size);
+        }
 
-                    if !is_in_motion {
-                        draw(
-                            renderer,
-                            text_color,
-                            child,
-                            cell.raw(),
-                            padding,
-                            &clipped_viewport,
-                        )
-                    }
-                }
-            }
+        let back = self.page_back.min_bounds().expand(self.pages_padding);
+        let next = self.page_next.min_bounds().expand(self.pages_padding);
 
-            if let Some(Editing::Cell {
-                index,
-                is_header: false,
-                ..
-            }) = &self.editing
-            {
-                if idx == *index {
-                    editing.replace(child.bounds().shrink(padding));
-                }
-            }
+        let mut pages = vec![];
+        let mut offset = 0.0;
+
+        // Create layout nodes
+        for _ in 0..count {
+            let node = Node::new(min_bounds).translate(Vector::new(offset, 0.0));
+
+            pages.push(node);
+            offset += min_bounds.width + gap;
         }
 
-        if let Some(size) = top_left {
-            let bounds = Rectangle::new(layout.position(), size);
+        let mut total_width = min_bounds.width * pages.len() as f32;
 
-            if let Some(clipped) = bounds.intersection(&og_viewport) {
-                <Renderer as advanced::Renderer>::fill_quad(
-                    renderer,
-                    Quad {
-                        bounds: clipped,
-                        ..Default::default()
-                    },
-                    style.header_background,
-                );
-            }
+        if !pages.is_empty() {
+            total_width += gap * (pages.len() - 1) as f32;
         }
 
-        match (editing, &self.editing) {
-            (
-                Some(bounds),
-                Some(Editing::Cell {
-                    index,
-                    value,
-                    is_header: true,
-                }),
-            ) => {
-                let (cell, _) = &self.headers[*index];
-                if let Some(clipped_bounds) = header_viewport.intersection(&bounds) {
-                    self.draw_edit(
-                        renderer,
-                        style,
-                        cell,
-                        clipped_bounds,
-                        bounds,
-                        value,
-                        cell.horizontal_alignment(),
-                    )
-                }
-            }
-            (
-                Some(bounds),
-                Some(Editing::Cell {
-                    index,
-                    value,
-                    is_header: false,
-                }),
-            ) => {
-                let cell = &self.cells[*index];
-                if let Some(clipped_bounds) = cell_viewport.intersection(&bounds) {
-                    self.draw_edit(
-                        renderer,
-                        style,
-                        cell,
-                        clipped_bounds,
-                        bounds,
-                        value,
-                        cell.horizontal_alignment(),
-                    )
-                }
-            }
-            _ => {}
-        };
+        let mut offset_x = 0.0;
+        let offset_y = 0.0;
+
+        let back = Node::new(back).translate(Vector::new(offset_x, offset_y));
+
+        offset_x += back.size().width + (gap * 1.5);
+
+        let pages = Node::with_children(Size::new(total_width, min_bounds.height), pages)
+            .translate(Vector::new(offset_x, offset_y));
+
+        offset_x += pages.size().width + (gap * 1.5);
+
+        let next = Node::new(next).translate(Vector::new(offset_x, offset_y));
+
+        offset_x += next.size().width;
+
+        let total_size = Size::new(
+            offset_x,
+            back.size()
+                .height
+                .max(pages.size().height.max(next.size().height)),
+        );
+
+        Node::with_children(total_size, vec![back, pages, next])
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn draw_edit(
-        &self,
-        renderer: &mut Renderer,
-        style: Style,
-        cell: &Cell<Renderer>,
-        clipped_bounds: Rectangle,
-        full_bounds: Rectangle,
-        value: &str,
-        alignment: Horizontal,
-    ) {
-        let (cursor, offset, is_selecting) = if let Some(focus) = self
-            .is_focused
-            .as_ref()
-            .filter(|focus| focus.is_window_focused)
-        {
-            let min_bounds = cell.min_bounds();
-            let y = full_bounds.y + ((full_bounds.height - min_bounds.height).max(0.0) * 0.5);
-            let y2 = y + min_bounds.height;
-            let y = y.max(clipped_bounds.y);
-            let height = (y2 - y).max(0.0);
+    fn layout_search<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        font: Renderer::Font,
+    ) -> Node {
+        if !table.searchable {
+            return Node::default();
+        }
 
-            match self.cursor.state(value) {
-                utils::State::Index(position) => {
-                    let (text_value_width, offset) =
-                        measure_cursor_and_scroll_offset(cell.raw(), clipped_bounds, position);
+        let (input, value) = &mut self.search_input;
 
-                    let is_cursor_visible = ((focus.now - focus.updated_at).as_millis()
-                        / CURSOR_BLINK_INTERVAL_MILLIS)
-                        % 2
-                        == 0;
+        input.update(super::text::<Renderer>(
+            value,
+            self.max_cell,
+            font,
+            Horizontal::Left,
+            self.page_size,
+            self.shaping,
+            self.line_height,
+        ));
 
-                    let cursor = if is_cursor_visible {
-                        Some((
-                            Quad {
-                                bounds: Rectangle {
-                                    x: (clipped_bounds.x + text_value_width).floor(),
-                                    y,
-                                    width: 1.0,
-                                    height,
-                                },
-                                ..Quad::default()
-                            },
-                            style.cursor_color,
-                        ))
-                    } else {
-                        None
-                    };
+        self.search_placeholder.update(super::text::<Renderer>(
+            SEARCH,
+            self.max_cell,
+            font,
+            Horizontal::Left,
+            self.page_size,
+            self.shaping,
+            self.line_height,
+        ));
 
-                    (cursor, offset, false)
-                }
-                utils::State::Selection { start, end } => {
-                    let left = start.min(end);
-                    let right = end.max(start);
+        let height = input
+            .min_bounds()
+            .height
+            .max(self.search_placeholder.min_bounds().height);
 
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(cell.raw(), clipped_bounds, left);
+        Node::new(Size::new(Self::SEARCH_WIDTH, height).expand(self.pages_padding))
+    }
+
+    fn layout_goto<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        font: Renderer::Font,
+    ) -> Node {
+        if table.raw.is_empty() {
+            return Node::with_children(Size::ZERO, vec![Node::default(); 3]);
+        }
+        let max = Cell::<Renderer>::new(super::text::<Renderer>(
+            &(table.pages_end() + 1).to_string(),
+            self.max_cell,
+            font,
+            Horizontal::Right,
+            self.page_size,
+            self.shaping,
+            self.line_height,
+        ));
 
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(cell.raw(), clipped_bounds, right);
+        let page = self.goto_page.min_bounds().expand(self.pages_padding);
+        let go = self.goto_go.min_bounds().expand(self.pages_padding);
 
-                    let width = right_position - left_position;
+        let (input, value) = &mut self.goto_input;
 
-                    (
-                        Some((
-                            Quad {
-                                bounds: Rectangle {
-                                    x: clipped_bounds.x + left_position,
-                                    y,
-                                    width,
-                                    height,
-                                },
-                                ..Quad::default()
-                            },
-                            style.cursor_selection,
-                        )),
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
-                        },
-                        true,
-                    )
+        input.update(super::text::<Renderer>(
+            value,
+            self.max_cell,
+            font,
+            Horizontal::Right,
+            self.page_size,
+            self.shaping,
+            self.line_height,
+        ));
+
+        let min_bounds = max.min_bounds();
+        let input = Size::new(min_bounds.width + 5.0, min_bounds.height).expand(self.pages_padding);
+
+        let mut offset = 0.0;
+
+        let page = Node::new(page).translate(Vector::new(offset, 0.0));
+
+        offset += page.size().width + (self.pages_gap * 1.5);
+
+        let input = Node::new(input).translate(Vector::new(offset, 0.0));
+
+        offset += input.size().width + (self.pages_gap * 1.5);
+
+        let go = Node::new(go).translate(Vector::new(offset, 0.0));
+
+        offset += go.size().width;
+
+        let total_size = Size::new(offset, page.size().max(input.size().max(go.size())).height);
+
+        Node::with_children(total_size, vec![page, input, go])
+    }
+
+    fn layout_page_sizes<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        font: Renderer::Font,
+    ) -> Node {
+        if table.raw.is_empty() || !table.shows_page_sizes() {
+            return Node::with_children(Size::ZERO, vec![Node::default(); 2]);
+        }
+
+        let gap = self.pages_gap;
+
+        self.page_sizes
+            .resize_with(table.page_sizes.len(), Cell::<Renderer>::default);
+
+        let mut min_bounds = Size::ZERO;
+
+        for (cell, size) in self.page_sizes.iter_mut().zip(table.page_sizes.iter()) {
+            cell.update(super::text::<Renderer>(
+                &size.to_string(),
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                self.page_size,
+                self.shaping,
+                self.line_height,
+            ));
+
+            min_bounds = min_bounds.max(cell.min_bounds().expand(self.pages_padding));
+        }
+
+        let mut sizes = vec![];
+        let mut offset = 0.0;
+
+        for _ in 0..self.page_sizes.len() {
+            let node = Node::new(min_bounds).translate(Vector::new(offset, 0.0));
+
+            sizes.push(node);
+            offset += min_bounds.width + gap;
+        }
+
+        let total_width = offset - gap;
+
+        let label = Node::new(
+            self.page_sizes_label
+                .min_bounds()
+                .expand(self.pages_padding),
+        );
+
+        let offset = label.size().width + (gap * 1.5);
+
+        let sizes = Node::with_children(Size::new(total_width, min_bounds.height), sizes)
+            .translate(Vector::new(offset, 0.0));
+
+        let total_size = Size::new(
+            offset + sizes.size().width,
+            label.size().height.max(sizes.size().height),
+        );
+
+        Node::with_children(total_size, vec![label, sizes])
+    }
+
+    /// Cuts the single line `value` shaped in `paragraph` down to the
+    /// `available` width, ending it with an ellipsis of width `ellipsis`.
+    #[allow(clippy::too_many_arguments)]
+    fn truncate(
+        paragraph: &mut Cell<Renderer>,
+        value: &str,
+        available: f32,
+        ellipsis: f32,
+        bounds: Size,
+        font: Renderer::Font,
+        size: Pixels,
+        shaping: Shaping,
+        line_height: LineHeight,
+    ) {
+        let mut cut = paragraph
+            .raw()
+            .hit_test(Point::new(
+                (available - ellipsis).max(0.0),
+                paragraph.min_bounds().height / 2.0,
+            ))
+            .map(text::Hit::cursor)
+            .unwrap_or_default()
+            .min(value.len());
+
+        while !value.is_char_boundary(cut) {
+            cut -= 1;
+        }
+
+        let truncated = format!("{}{OVERFLOW_ELLIPSIS}", value[..cut].trim_end());
+        let horizontal = paragraph.horizontal_alignment();
+        paragraph.update(super::text::<Renderer>(
+            &truncated,
+            bounds,
+            font,
+            horizontal,
+            size,
+            shaping,
+            line_height,
+        ));
+    }
+
+    fn layout_status<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        font: Renderer::Font,
+        max_width: f32,
+    ) -> Node {
+        if table.raw.is_empty() {
+            return Node::default();
+        }
+
+        let bounds = Size::new(max_width, f32::INFINITY);
+        let error = self.edit_error.clone().or_else(|| {
+            self.selection
+                .as_ref()
+                .and_then(|selection| selection.single_cell())
+                .and_then(|(row, column)| table.cell_error(row, column))
+        });
+        let (cell, value) = &mut self.status;
+        let value = match (error.as_ref(), table.status.as_ref(), self.notice.as_ref()) {
+            (Some(error), _, _) => error,
+            (None, Some(status), _) => status,
+            (None, None, Some((notice, _))) => notice,
+            (None, None, None) => value,
+        };
+
+        let padding = self.pages_padding;
+
+        if table.status_left.is_none() && table.status_right.is_none() {
+            cell.update(super::text::<Renderer>(
+                value,
+                bounds,
+                font,
+                Horizontal::Center,
+                self.page_size,
+                self.shaping,
+                self.line_height,
+            ));
+
+            let center = Node::new(cell.min_bounds()).translate([padding.left, padding.top]);
+            let size = cell.min_bounds().expand(padding);
+
+            // Segments removed since the last layout are not drawn again
+            self.status_segments = Default::default();
+
+            return Node::with_children(size, vec![Node::default(), center, Node::default()]);
+        }
+
+        // Each segment keeps to a single line, truncated to its share
+        let size = self.page_size;
+        self.status_ellipsis.update(super::text::<Renderer>(
+            OVERFLOW_ELLIPSIS,
+            self.max_cell,
+            font,
+            Horizontal::Left,
+            size,
+            self.shaping,
+            self.line_height,
+        ));
+        let ellipsis = self.status_ellipsis.min_width();
+
+        let inner = (max_width - padding.horizontal()).max(0.0);
+        let spacing = self.pages_padding.horizontal();
+        let fit = |cell: &mut Cell<Renderer>, value: &str, available: f32| {
+            cell.update(super::text::<Renderer>(
+                value,
+                self.max_cell,
+                font,
+                Horizontal::Left,
+                size,
+                self.shaping,
+                self.line_height,
+            ));
+
+            if cell.min_width() > available {
+                Self::truncate(
+                    cell,
+                    value,
+                    available,
+                    ellipsis,
+                    self.max_cell,
+                    font,
+                    size,
+                    self.shaping,
+                    self.line_height,
+                );
+            }
+
+            cell.min_bounds()
+        };
+
+        let (left, right) = &mut self.status_segments;
+        let left = fit(
+            left,
+            table.status_left.as_deref().unwrap_or_default(),
+            inner / 3.0,
+        );
+        let right = fit(
+            right,
+            table.status_right.as_deref().unwrap_or_default(),
+            inner / 3.0,
+        );
+        let center = fit(
+            cell,
+            value,
+            (inner - left.width - right.width - (2.0 * spacing)).max(0.0),
+        );
+
+        let height = left.height.max(center.height).max(right.height);
+        let segment = |width: f32, x: f32| {
+            Node::new(Size::new(width, height)).translate([padding.left + x, padding.top])
+        };
+
+        let children = vec![
+            segment(left.width, 0.0),
+            segment(center.width, (inner - center.width) / 2.0),
+            segment(right.width, inner - right.width),
+        ];
+
+        Node::with_children(Size::new(inner, height).expand(padding), children)
+    }
+
+    pub fn layout<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        limits: Limits,
+    ) -> Node {
+        let font = table.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = table.text_size.unwrap_or_else(|| renderer.default_size());
+        let spacing = if table.raw.is_empty() {
+            0.0
+        } else {
+            table.spacing
+        };
+
+        self.pre_layout(table, font, text_size);
+
+        if table.loading {
+            self.loading.update(super::text::<Renderer>(
+                LOADING,
+                self.max_cell,
+                font,
+                Horizontal::Center,
+                text_size,
+                self.shaping,
+                self.line_height,
+            ));
+        }
+
+        let padding = table.padding;
+
+        let content_limits = limits
+            .width(table.width)
+            .height(table.height)
+            .shrink(table.padding);
+
+        let mut pagination = if table.multiple_pages() {
+            self.layout_pagination(table, font, false)
+        } else {
+            Node::default()
+        };
+
+        let mut goto = if table.multiple_pages() {
+            self.layout_goto(table, font)
+        } else {
+            Node::default()
+        };
+        let goto_size = goto.size();
+
+        let mut sizes = self.layout_page_sizes(table, font);
+        let sizes_size = sizes.size();
+
+        let spaced = |widths: [f32; 3]| {
+            let shown = widths.iter().filter(|width| **width > 0.0).count();
+            widths.iter().sum::<f32>() + spacing * shown.saturating_sub(1) as f32
+        };
+
+        // Narrow tables only show the current page out of the total
+        if table.multiple_pages()
+            && spaced([pagination.size().width, goto_size.width, sizes_size.width])
+                > content_limits.max().width
+        {
+            pagination = self.layout_pagination(table, font, true);
+        }
+        let pagination_size = pagination.size();
+
+        let widths = [pagination_size.width, goto_size.width, sizes_size.width];
+
+        let actions = Size::new(
+            spaced(widths),
+            pagination_size
+                .height
+                .max(goto_size.height)
+                .max(sizes_size.height),
+        );
+
+        let actions_spacing = if table.has_actions() { spacing } else { 0.0 };
+
+        let cells = self.layout_cells(table, renderer, font, content_limits.max().width);
+        let cells_size = cells.size();
+
+        // Unbounded tables split the status segments across the cells instead
+        let status_width = match content_limits.max().width {
+            width if width.is_finite() => width,
+            _ => cells_size.width,
+        };
+
+        let search = self.layout_search(table, font);
+        let search_size = search.size();
+        let search_spacing = if table.searchable { spacing } else { 0.0 };
+        let status_width = (status_width - search_size.width - search_spacing).max(0.0);
+
+        let mut status = self.layout_status(table, font, status_width);
+        let status_size = Size::new(
+            status.size().width,
+            status.size().height.max(search_size.height),
+        );
+        status.translate_mut(Vector::new(
+            padding.left,
+            padding.top + actions.height + actions_spacing,
+        ));
+
+        let cells = cells.translate(Vector::new(
+            padding.left,
+            padding.top + actions.height + actions_spacing + status_size.height + spacing,
+        ));
+
+        let footer = self.layout_footer(table, renderer, font);
+
+        let total_size = Size::new(
+            actions.width.max(cells_size.width),
+            actions.height
+                + actions_spacing
+                + status_size.height
+                + spacing
+                + cells_size.height
+                + self.footer_height,
+        )
+        .expand(padding);
+
+        let size = limits.resolve(table.width, table.height, total_size);
+
+        let footer = footer.translate(Vector::new(
+            padding.left,
+            size.height - padding.bottom - self.footer_height,
+        ));
+
+        let search = search.translate(Vector::new(
+            size.width - padding.right - search_size.width,
+            padding.top + actions.height + actions_spacing,
+        ));
+
+        let diff = (size.width - actions.width) * 0.5;
+
+        let mut offset_x = diff;
+        let offset_y = padding.top;
+
+        for node in [&mut pagination, &mut goto, &mut sizes] {
+            if node.size().width > 0.0 {
+                node.translate_mut([offset_x, offset_y]);
+                offset_x += node.size().width + spacing;
+            }
+        }
+
+        let children = vec![cells, status, pagination, goto, sizes, footer, search];
+
+        Node::with_children(size, children)
+    }
+
+    /// Returns the zero-based page the pagination ellipsis at `idx` moves to,
+    /// if any.
+    fn ellipsis_target<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        idx: usize,
+    ) -> Option<usize> {
+        let page = |idx: usize| {
+            self.paginations
+                .get(idx)
+                .and_then(|(_, value)| value.parse::<usize>().ok())
+        };
+
+        // Both neighbours are one-based page numbers
+        let left = page(idx.checked_sub(1)?)?;
+        let right = page(idx + 1)?;
+
+        match table.ellipsis_jump {
+            EllipsisJump::Midpoint => Some((left + (right - left) / 2).saturating_sub(1)),
+            EllipsisJump::Step(step) if left > self.page => {
+                Some((self.page + step).min(table.pages_end()))
+            }
+            EllipsisJump::Step(step) => Some(self.page.saturating_sub(step)),
+            EllipsisJump::None => None,
+        }
+    }
+
+    /// Returns `true` if the pagination entry at `idx` cannot be clicked.
+    fn is_inert_page<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        idx: usize,
+    ) -> bool {
+        if self.compact_pagination {
+            return true;
+        }
+
+        table.ellipsis_jump == EllipsisJump::None
+            && self
+                .paginations
+                .get(idx)
+                .is_some_and(|(_, value)| value == PAGINATION_ELLIPSIS)
+    }
+
+    fn draw_pages<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for (idx, ((cell, content), layout)) in
+            self.paginations.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+            let (background, text_color) = if (self.page + 1).to_string() == *content {
+                (style.selected_page_background, style.selected_page_text)
+            } else if cursor.is_over(bounds) && !self.is_inert_page(table, idx) {
+                (style.hovered_page_background, style.hovered_page_text)
+            } else {
+                (style.page_background, style.page_text)
+            };
+
+            if let Some(clipped_viewport) = bounds.intersection(viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        border: style.page_border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+
+                draw(
+                    renderer,
+                    text_color,
+                    layout,
+                    cell.raw(),
+                    self.pages_padding,
+                    &clipped_viewport,
+                )
+            }
+        }
+    }
+
+    fn draw_pagination<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        {
+            let back = children.next().expect("Missing paginations: Back");
+
+            let (background, text_color) = if self.page == 0 {
+                (
+                    style.pagination_background.scale_alpha(0.5),
+                    style.pagination_text.scale_alpha(0.5),
+                )
+            } else if cursor.is_over(back.bounds()) {
+                (
+                    style.hovered_pagination_background,
+                    style.hovered_pagination_text,
+                )
+            } else {
+                (style.pagination_background, style.pagination_text)
+            };
+
+            if let Some(bounds) = back.bounds().intersection(viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds,
+                        border: style.pagination_border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+                draw(
+                    renderer,
+                    text_color,
+                    back,
+                    self.page_back.raw(),
+                    self.pages_padding,
+                    viewport,
+                );
+            }
+        };
+
+        let pages = children.next().expect("Missing paginations: Pages");
+
+        self.draw_pages(table, renderer, pages, style, cursor, viewport);
+
+        {
+            let next = children.next().expect("Missing paginations: Next");
+
+            let (background, text_color) = if self.page == table.pages_end() {
+                (
+                    style.pagination_background.scale_alpha(0.5),
+                    style.pagination_text.scale_alpha(0.5),
+                )
+            } else if cursor.is_over(next.bounds()) {
+                (
+                    style.hovered_pagination_background,
+                    style.hovered_pagination_text,
+                )
+            } else {
+                (style.pagination_background, style.pagination_text)
+            };
+
+            if let Some(bounds) = next.bounds().intersection(viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds,
+                        border: style.pagination_border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+                draw(
+                    renderer,
+                    text_color,
+                    next,
+                    self.page_next.raw(),
+                    self.pages_padding,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn draw_scrollbars<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        cells_viewport: Rectangle,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let radius = table.scrollbar_width * 0.5;
+
+        for scrollbar in self.scrollbars(table, cells_viewport).into_iter().flatten() {
+            let is_dragged = self
+                .scrollbar_drag
+                .is_some_and(|(is_vertical, _)| is_vertical == scrollbar.is_vertical);
+
+            let thumb = if is_dragged || cursor.is_over(scrollbar.thumb) {
+                style.hovered_scrollbar_thumb
+            } else {
+                style.scrollbar_thumb
+            };
+
+            for (bounds, background) in [
+                (scrollbar.track, style.scrollbar_track),
+                (scrollbar.thumb, thumb),
+            ] {
+                if let Some(clipped) = bounds.intersection(viewport) {
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds: clipped,
+                            border: Border::default().rounded(radius),
+                            ..Default::default()
+                        },
+                        background,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_page_sizes<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+
+        let label = children
+            .next()
+            .expect("Widget draw: Missing page sizes label");
+
+        if let Some(bounds) = label.bounds().intersection(viewport) {
+            draw(
+                renderer,
+                style.goto_page_text,
+                label,
+                self.page_sizes_label.raw(),
+                self.pages_padding,
+                &bounds,
+            );
+        }
+
+        let sizes = children
+            .next()
+            .expect("Widget draw: Missing page sizes presets");
+
+        for ((cell, size), layout) in self
+            .page_sizes
+            .iter()
+            .zip(table.page_sizes.iter())
+            .zip(sizes.children())
+        {
+            let bounds = layout.bounds();
+            let (background, text_color) = if *size == table.page_limit {
+                (style.selected_page_background, style.selected_page_text)
+            } else if cursor.is_over(bounds) {
+                (style.hovered_page_background, style.hovered_page_text)
+            } else {
+                (style.page_background, style.page_text)
+            };
+
+            if let Some(clipped_viewport) = bounds.intersection(viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        border: style.page_border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+
+                draw(
+                    renderer,
+                    text_color,
+                    layout,
+                    cell.raw(),
+                    self.pages_padding,
+                    &clipped_viewport,
+                )
+            }
+        }
+    }
+
+    fn draw_search(
+        &self,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        viewport: &Rectangle,
+    ) {
+        let Some(bounds) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        <Renderer as advanced::Renderer>::fill_quad(
+            renderer,
+            Quad {
+                bounds,
+                border: style.goto_border,
+                ..Default::default()
+            },
+            style.goto_input_background,
+        );
+
+        let (cell, color) = if self.search_input.1.is_empty() {
+            (&self.search_placeholder, style.placeholder_text)
+        } else {
+            (&self.search_input.0, style.goto_input_text)
+        };
+
+        if let Some(clipped) = bounds.shrink(self.pages_padding).intersection(&bounds) {
+            draw(
+                renderer,
+                color,
+                layout,
+                cell.raw(),
+                self.pages_padding,
+                &clipped,
+            );
+        }
+    }
+
+    fn draw_goto(
+        &self,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        {
+            let page = children.next().expect("Widget draw: Missing Goto Page");
+
+            if let Some(bounds) = page.bounds().intersection(viewport) {
+                draw(
+                    renderer,
+                    style.goto_page_text,
+                    page,
+                    self.goto_page.raw(),
+                    self.pages_padding,
+                    &bounds,
+                );
+            }
+        }
+
+        {
+            let input = children.next().expect("Widget draw: Missing Goto Input");
+
+            if let Some(bounds) = input.bounds().intersection(viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds,
+                        ..Default::default()
+                    },
+                    style.goto_input_background,
+                );
+                draw(
+                    renderer,
+                    style.goto_input_text,
+                    input,
+                    self.goto_input.0.raw(),
+                    self.pages_padding,
+                    viewport,
+                );
+            }
+        }
+
+        {
+            let go = children.next().expect("Widget draw: Missing Goto Go");
+
+            if let Some(bounds) = go.bounds().intersection(viewport) {
+                let (background, text_color) = if cursor.is_over(go.bounds()) {
+                    (style.hovered_goto_background, style.hovered_goto_text)
+                } else {
+                    (style.goto_background, style.goto_text)
+                };
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds,
+                        border: style.goto_border,
+                        ..Default::default()
+                    },
+                    background,
+                );
+                draw(
+                    renderer,
+                    text_color,
+                    go,
+                    self.goto_go.raw(),
+                    self.pages_padding,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_footer(
+        &self,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cells_viewport: Rectangle,
+        viewport: &Rectangle,
+        padding: Padding,
+        frozen_columns: usize,
+    ) {
+        if self.footer.is_empty() {
+            return;
+        }
+
+        let bounds = {
+            let numbering = cells_viewport.x - layout.bounds().x;
+            let size = Size::new(cells_viewport.width + numbering, layout.bounds().height);
+
+            Rectangle::new(layout.bounds().position(), size)
+        };
+
+        let Some(bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        <Renderer as advanced::Renderer>::fill_quad(
+            renderer,
+            Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.footer_background,
+        );
+
+        // The footer scrolls horizontally under the numbering column
+        let columns = Rectangle::new(
+            Point::new(cells_viewport.x, bounds.y),
+            Size::new(cells_viewport.width, bounds.height),
+        );
+
+        let Some(columns) = columns.intersection(&bounds) else {
+            return;
+        };
+
+        for (column, (cell, layout)) in self.footer.iter().zip(layout.children()).enumerate() {
+            let child = layout
+                .children()
+                .next()
+                .expect("Table draw: Footer node missing child layout");
+
+            let columns = if column < frozen_columns {
+                columns
+            } else {
+                self.scrolled_viewport(columns)
+            };
+
+            if let Some(clipped_viewport) = child.bounds().intersection(&columns) {
+                draw(
+                    renderer,
+                    style.footer_text,
+                    child,
+                    cell.raw(),
+                    padding,
+                    &clipped_viewport,
+                )
+            }
+        }
+    }
+
+    fn draw_status(
+        &self,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        viewport: &Rectangle,
+    ) {
+        if let Some(bounds) = layout.bounds().intersection(viewport) {
+            <Renderer as advanced::Renderer>::fill_quad(
+                renderer,
+                Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                style.status_background,
+            );
+
+            let (left, right) = &self.status_segments;
+
+            for (layout, cell) in layout.children().zip([left, &self.status.0, right]) {
+                draw(
+                    renderer,
+                    style.status_text,
+                    layout,
+                    cell.raw(),
+                    Padding::ZERO,
+                    &bounds,
+                )
+            }
+        }
+    }
+
+    /// Returns the bounds of the top-left corner where the numbering column
+    /// meets the headers row.
+    fn corner_bounds(layout: layout::Layout<'_>) -> Rectangle {
+        let mut children = layout.children();
+        let numbering = children.next().map(|numbering| numbering.bounds().width);
+        let headers = children.next().map(|headers| headers.bounds().height);
+
+        Rectangle::new(
+            layout.position(),
+            Size::new(numbering.unwrap_or_default(), headers.unwrap_or_default()),
+        )
+    }
+
+    /// Returns the data columns in view of a `width` wide [`Table`] at the
+    /// last layout, widened by [`State::OVERSCAN`] columns on both sides.
+    ///
+    /// Columns never measured are only as wide as their padding, so they come
+    /// into view, and get measured, as soon as the columns before them do.
+    fn visible_columns<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        width: f32,
+    ) -> Range<usize> {
+        let columns = table.cols.min(self.min_widths.len().saturating_sub(1));
+
+        if !width.is_finite() {
+            return 0..columns;
+        }
+
+        let left = self.frozen_width - self.scroll_offset.x;
+        let right = width - self.min_widths[0] - self.scroll_offset.x;
+
+        let mut x = 0.0;
+        let mut start = columns;
+        let mut end = columns;
+
+        for column in 0..columns {
+            if x >= right {
+                end = column;
+                break;
+            }
+
+            x += self.column_span(table, column);
+
+            if start == columns && x > left {
+                start = column;
+            }
+        }
+
+        start.min(end).saturating_sub(Self::OVERSCAN)..(end + Self::OVERSCAN).min(columns)
+    }
+
+    /// Returns the bounds of the input being edited, if any.
+    fn editing_bounds(&self, cells: layout::Layout<'_>) -> Option<Rectangle> {
+        match self.editing.as_ref()? {
+            Editing::Goto(bounds) | Editing::Search(bounds) => Some(*bounds),
+            Editing::Cell {
+                index, is_header, ..
+            } => {
+                let mut children = cells.children().skip(1);
+                let headers = children.next()?;
+
+                let cell = if *is_header {
+                    headers.children().nth(*index)
+                } else {
+                    children.next()?.children().nth(*index)
+                }?;
+
+                Some(cell.bounds())
+            }
+        }
+    }
+
+    /// Returns the width `column` takes up along with its gap, which is
+    /// nothing for hidden columns.
+    fn column_span<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        column: usize,
+    ) -> f32 {
+        if table.is_hidden(column) {
+            return 0.0;
+        }
+
+        self.min_widths
+            .get(column + 1)
+            .map_or(0.0, |width| width + self.cell_gap)
+    }
+
+    /// Returns the horizontal offset undoing the scrolling of `column` if it
+    /// is frozen.
+    fn frozen_shift<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        column: usize,
+    ) -> f32 {
+        if column < table.frozen_columns {
+            -self.scroll_offset.x
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the part of the columns `viewport` the scrolled columns are
+    /// shown in, right of the frozen columns.
+    fn scrolled_viewport(&self, viewport: Rectangle) -> Rectangle {
+        let width = self.frozen_width.min(viewport.width);
+
+        Rectangle::new(
+            viewport.position() + Vector::new(width, 0.0),
+            Size::new(viewport.width - width, viewport.height),
+        )
+    }
+
+    /// Returns the bounds of the sort arrow right of a header `label`.
+    fn sort_bounds(&self, label: Rectangle) -> Rectangle {
+        Rectangle::new(
+            Point::new(label.x + label.width, label.y),
+            Size::new(self.sort_width, label.height),
+        )
+    }
+
+    /// Publishes an [`Action::Sort`] cycling `column` from unsorted to
+    /// ascending to descending.
+    fn sort<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        column: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let order = match table.sorted_by {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => Some(SortOrder::Descending),
+            Some((sorted, SortOrder::Descending)) if sorted == column => None,
+            _ => Some(SortOrder::Ascending),
+        };
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            let msg = on_action(Action::Sort { column, order });
+            shell.publish(msg);
+        }
+    }
+
+    /// Grows `bounds` over the gaps to its `right` and `bottom`, leaving
+    /// `line_width` of them for the grid lines.
+    fn grid_bounds(
+        &self,
+        bounds: Rectangle,
+        line_width: f32,
+        right: bool,
+        bottom: bool,
+    ) -> Rectangle {
+        let inset = (self.cell_gap - line_width).max(0.0);
+        let width = if right { inset } else { 0.0 };
+        let height = if bottom { inset } else { 0.0 };
+
+        Rectangle::new(bounds.position(), bounds.size() + Size::new(width, height))
+    }
+
+    /// Outlines the part of a block selection of `rows` and `columns` on the
+    /// current page, from the bounds of its first and last cells so resized
+    /// rows and columns leave no gaps.
+    ///
+    /// Edges continuing onto other pages are left open.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_block_border<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        cells: layout::Layout<'_>,
+        style: Style,
+        rows: RangeInclusive<usize>,
+        columns: RangeInclusive<usize>,
+        viewport: Rectangle,
+    ) {
+        if table.rows == 0 || table.cols == 0 {
+            return;
+        }
+
+        let (page_start, page_end) = self.page_rows(table).into_inner();
+
+        let (first_row, last_row) = ((*rows.start()).max(page_start), (*rows.end()).min(page_end));
+        let last_column = (*columns.end()).min(table.cols - 1);
+
+        if first_row > last_row || *columns.start() > last_column {
+            return;
+        }
+
+        let bounds = |row: usize, column: usize| {
+            cells
+                .children()
+                .nth(column * table.page_limit + row - page_start)
+                .and_then(|layout| layout.children().next())
+                .map(|layout| layout.bounds())
+        };
+
+        let (Some(first), Some(last)) = (
+            bounds(first_row, *columns.start()),
+            bounds(last_row, last_column),
+        ) else {
+            return;
+        };
+
+        let gap = self.cell_gap;
+        let outline = first.union(&last).expand(gap);
+        let viewport = if *columns.start() < table.frozen_columns {
+            viewport
+        } else {
+            self.scrolled_viewport(viewport)
+        };
+
+        let left = Rectangle::new(outline.position(), Size::new(gap, outline.height));
+        let right = Rectangle::new(
+            Point::new(outline.x + outline.width - gap, outline.y),
+            Size::new(gap, outline.height),
+        );
+        let top = (*rows.start() >= page_start)
+            .then(|| Rectangle::new(outline.position(), Size::new(outline.width, gap)));
+        let bottom = (*rows.end() <= page_end).then(|| {
+            Rectangle::new(
+                Point::new(outline.x, outline.y + outline.height - gap),
+                Size::new(outline.width, gap),
+            )
+        });
+
+        for edge in [Some(left), Some(right), top, bottom].into_iter().flatten() {
+            if let Some(clipped) = edge.intersection(&viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped,
+                        ..Default::default()
+                    },
+                    style.selected_cell_border,
+                );
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_cells<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: Rectangle,
+        padding: Padding,
+    ) {
+        let og_viewport = viewport;
+        // The column groups band only has grid lines under its groups
+        let grid = {
+            let bounds = layout.bounds();
+            let band = Vector::new(0.0, self.groups_height);
+
+            Rectangle::new(
+                bounds.position() + band,
+                bounds.size() - Size::new(0.0, band.y),
+            )
+        };
+        if let Some(clipped) = grid.intersection(&viewport) {
+            <Renderer as advanced::Renderer>::fill_quad(
+                renderer,
+                Quad {
+                    bounds: clipped,
+                    ..Default::default()
+                },
+                style.grid_line,
+            );
+        }
+
+        let line_width = table.grid_line_width;
+        let mut editing: Option<Rectangle> = None;
+
+        let mut children = layout.children();
+        let numbering = children
+            .next()
+            .expect("Widget draw: Missing numbering cells");
+        let headers = children.next().expect("Widget draw: Missing header cells");
+        let cells = children.next().expect("Widget draw: Missing cells layout");
+        let groups = children
+            .next()
+            .expect("Widget draw: Missing column groups layout");
+
+        let mut top_left: Option<Size> = None;
+
+        let numbering_viewport = {
+            let moved = viewport + Vector::new(0.0, headers.bounds().height);
+
+            let size = Size::new(moved.width, moved.height - headers.bounds().height);
+
+            Rectangle::new(moved.position(), size)
+        };
+
+        let is_dragging = self.resizing.is_some()
+            || self.is_text_dragging
+            || self.selection_drag.is_some()
+            || self.scrollbar_drag.is_some();
+
+        // The row of the page under the cursor, found through the first column
+        let hovered_row = cursor
+            .position_over(numbering_viewport)
+            .filter(|_| table.highlight_hovered_row && !is_dragging)
+            .and_then(|position| {
+                cells.children().take(table.page_limit).position(|cell| {
+                    let bounds = cell.bounds();
+                    (bounds.y..bounds.y + bounds.height).contains(&position.y)
+                })
+            });
+
+        for (idx, (number, layout)) in self.numbering.iter().zip(numbering.children()).enumerate() {
+            let bounds = layout.bounds();
+
+            if let Some(clipped_viewport) = bounds.intersection(&numbering_viewport) {
+                let child = layout
+                    .children()
+                    .next()
+                    .expect("Table draw: Resize node missing child layout");
+
+                top_left = Some(Size::new(child.bounds().width, 0.0));
+
+                let grid = self
+                    .grid_bounds(child.bounds(), line_width, true, true)
+                    .intersection(&clipped_viewport);
+
+                if let Some(clipped_viewport) = child.bounds().intersection(&clipped_viewport) {
+                    let grid = grid.unwrap_or(clipped_viewport);
+                    let (background, text_color) = if idx % 2 == 1 {
+                        (
+                            style.alternating_backgrounds.1,
+                            style.alternating_text_color.1,
+                        )
+                    } else {
+                        (
+                            style.alternating_backgrounds.0,
+                            style.alternating_text_color.0,
+                        )
+                    };
+
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds: grid,
+                            ..Default::default()
+                        },
+                        background,
+                    );
+
+                    if hovered_row.is_some_and(|row| row + 1 == idx) {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: grid,
+                                ..Default::default()
+                            },
+                            style.hovered_row_background,
+                        );
+                    }
+
+                    draw(
+                        renderer,
+                        text_color,
+                        child,
+                        number.raw(),
+                        padding,
+                        &clipped_viewport,
+                    )
+                }
+            }
+        }
+
+        let viewport = {
+            let moved = viewport + Vector::new(numbering.bounds().width, 0.0);
+
+            let size = Size::new(moved.width - numbering.bounds().width, moved.height);
+
+            Rectangle::new(moved.position(), size)
+        };
+
+        let header_viewport = viewport;
+
+        for (idx, ((header, kind), layout)) in
+            self.headers.iter().zip(headers.children()).enumerate()
+        {
+            let viewport = if idx < table.frozen_columns {
+                header_viewport
+            } else {
+                self.scrolled_viewport(header_viewport)
+            };
+
+            let pair = layout
+                .children()
+                .next()
+                .expect("Table draw: Resize node missing pair layout");
+
+            let mut children = pair.children();
+            let label = children
+                .next()
+                .expect("Table draw: Pair node missing label layout");
+            // Missing when the column kinds are hidden
+            let knd = children.next();
+
+            let is_selected = self
+                .selection
+                .as_ref()
+                .map(|selection| selection.header(idx))
+                .unwrap_or_default();
+
+            top_left = top_left
+                .map(|size| Size::new(size.width, pair.bounds().height + self.groups_height));
+
+            if is_selected {
+                let bounds = pair.bounds().expand([self.cell_gap, self.cell_gap]);
+                if let Some(clipped_viewport) = bounds.intersection(&viewport) {
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds: clipped_viewport,
+                            ..Default::default()
+                        },
+                        style.selected_header_border,
+                    );
+                }
+            }
+
+            let grid = self.grid_bounds(pair.bounds(), line_width, !is_selected, !is_selected);
+
+            if let Some(clipped_viewport) = grid.intersection(&viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        ..Default::default()
+                    },
+                    style.header_background,
+                );
+            }
+
+            if let Some(label_viewport) = label.bounds().intersection(&viewport) {
+                draw(
+                    renderer,
+                    style.header_text,
+                    label,
+                    header.raw(),
+                    Padding::from(0),
+                    &label_viewport,
+                )
+            }
+
+            if table.sortable {
+                let bounds = self.sort_bounds(label.bounds());
+                let (arrow, color) = match table.sorted_by {
+                    Some((column, SortOrder::Ascending)) if column == idx => {
+                        (&self.sort_arrows[1], style.header_text)
+                    }
+                    Some((column, SortOrder::Descending)) if column == idx => {
+                        (&self.sort_arrows[2], style.header_text)
+                    }
+                    _ => (&self.sort_arrows[0], style.header_type.scale_alpha(0.5)),
+                };
+
+                if let Some(arrow_viewport) = bounds.intersection(&viewport) {
+                    renderer.fill_paragraph(arrow.raw(), bounds.center(), color, arrow_viewport);
+                }
+            }
+
+            if let Some((knd, kind_viewport)) =
+                knd.and_then(|knd| Some((knd, knd.bounds().intersection(&viewport)?)))
+            {
+                draw(
+                    renderer,
+                    style.header_type,
+                    knd,
+                    kind.raw(),
+                    Padding::from(0),
+                    &kind_viewport,
+                )
+            }
+
+            if let Some(Editing::Cell {
+                index,
+                is_header: true,
+                ..
+            }) = &self.editing
+            {
+                if idx == *index {
+                    editing.replace(label.bounds());
+                }
+            }
+        }
+
+        let viewport = {
+            let moved = viewport + Vector::new(0.0, headers.bounds().height);
+
+            let size = Size::new(moved.width, moved.height - headers.bounds().height);
+
+            Rectangle::new(moved.position(), size)
+        };
+        let cell_viewport = viewport;
+
+        for (idx, (cell, layout)) in self.cells.iter().zip(cells.children()).enumerate() {
+            let viewport = if idx / table.page_limit < table.frozen_columns {
+                cell_viewport
+            } else {
+                self.scrolled_viewport(cell_viewport)
+            };
+
+            let bounds = layout.bounds();
+            let child = layout
+                .children()
+                .next()
+                .expect("Table draw: Resize node missing child layout");
+
+            if let Some(clipped_viewport) = bounds.intersection(&viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        ..Default::default()
+                    },
+                    Background::Color(Color::TRANSPARENT),
+                );
+
+                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                let row = row + (self.page * table.page_limit);
+
+                let is_in_motion = self
+                    .motion
+                    .as_ref()
+                    .map(|motion| motion.contains(row, column))
+                    .unwrap_or_default();
+
+                let (selection, is_selected) = self
+                    .selection
+                    .as_ref()
+                    .map(|selection| {
+                        // Blocks are outlined as a whole below
+                        let border = match selection {
+                            Selection::Block { .. } => 0,
+                            Selection::Scattered { .. } => selection.border(row, column),
+                        };
+
+                        (border, selection.contains(row, column))
+                    })
+                    .unwrap_or_default();
+
+                let is_invalid_edit = self.edit_error.is_some()
+                    && matches!(
+                        &self.editing,
+                        Some(Editing::Cell { index, is_header: false, .. }) if *index == idx
+                    );
+                let has_error = is_invalid_edit
+                    || (row < table.rows && table.cell_error(row, column).is_some());
+
+                if has_error {
+                    let bounds = child.bounds().expand(self.cell_gap / 2.0);
+
+                    if let Some(error_viewport) = bounds.intersection(&viewport) {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: error_viewport,
+                                border: iced::Border::default().rounded(2.0),
+                                ..Default::default()
+                            },
+                            style.error_cell_border,
+                        );
+                    }
+                }
+
+                let selection = {
+                    let mut padding = Padding::ZERO;
+
+                    if (selection & 1) == 1 {
+                        padding = padding.left(self.cell_gap);
+                    }
+
+                    if ((selection >> 1) & 1) == 1 {
+                        padding = padding.top(self.cell_gap);
+                    }
+
+                    if ((selection >> 2) & 1) == 1 {
+                        padding = padding.right(self.cell_gap);
+                    }
+
+                    if ((selection >> 3) & 1) == 1 {
+                        padding = padding.bottom(self.cell_gap);
+                    }
+
+                    padding
+                };
+
+                if let Some(selection_viewport) =
+                    child.bounds().expand(selection).intersection(&viewport)
+                {
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds: selection_viewport,
+                            border: iced::Border::default().rounded(2.0),
+                            ..Default::default()
+                        },
+                        style.selected_cell_border,
+                    );
+                }
+
+                // Selection and error borders show through the gaps instead
+                let grid = self
+                    .grid_bounds(
+                        child.bounds(),
+                        line_width,
+                        selection.right == 0.0 && !has_error,
+                        selection.bottom == 0.0 && !has_error,
+                    )
+                    .intersection(&clipped_viewport);
+
+                if let Some(clipped_viewport) = child.bounds().intersection(&clipped_viewport) {
+                    let grid = grid.unwrap_or(clipped_viewport);
+                    let cell_style = if row < table.rows {
+                        table.styled_cell(row, column).unwrap_or_default()
+                    } else {
+                        CellStyle::default()
+                    };
+
+                    let row = idx % table.page_limit;
+
+                    let (cell_background, text_color) = if row % 2 == 0 {
+                        (
+                            style.alternating_backgrounds.1,
+                            style.alternating_text_color.1,
+                        )
+                    } else {
+                        (
+                            style.alternating_backgrounds.0,
+                            style.alternating_text_color.0,
+                        )
+                    };
+
+                    let cell_background = cell_style.background.unwrap_or(cell_background);
+                    let text_color = cell_style.text_color.unwrap_or(text_color);
+
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds: grid,
+                            border: cell_style.border.unwrap_or_default(),
+                            ..Default::default()
+                        },
+                        cell_background,
+                    );
+
+                    if has_error {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: grid,
+                                ..Default::default()
+                            },
+                            style.error_cell_background,
+                        );
+                    }
+
+                    if self.matches.contains(&idx) {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: grid,
+                                ..Default::default()
+                            },
+                            style.match_background,
+                        );
+                    }
+
+                    if hovered_row == Some(row) && !is_selected {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: grid,
+                                ..Default::default()
+                            },
+                            style.hovered_row_background,
+                        );
+                    }
+
+                    if is_selected && self.editing.is_none() {
+                        <Renderer as advanced::Renderer>::fill_quad(
+                            renderer,
+                            Quad {
+                                bounds: grid,
+                                ..Default::default()
+                            },
+                            style.selected_cell_background,
+                        );
+                    }
+
+                    let text_color = if self.placeholders.contains(&idx) {
+                        style.placeholder_text
+                    } else {
+                        text_color
+                    };
+
+                    if !is_in_motion {
+                        draw(
+                            renderer,
+                            text_color,
+                            child,
+                            cell.raw(),
+                            padding,
+                            &clipped_viewport,
+                        )
+                    }
+                }
+            }
+
+            if let Some(Editing::Cell {
+                index,
+                is_header: false,
+                ..
+            }) = &self.editing
+            {
+                if idx == *index {
+                    editing.replace(child.bounds().shrink(padding));
+                }
+            }
+        }
+
+        if let Some(Selection::Block { rows, columns }) = self.selection.as_ref() {
+            self.draw_block_border(
+                table,
+                renderer,
+                cells,
+                style,
+                rows.clone(),
+                columns.clone(),
+                cell_viewport,
+            );
+        }
+
+        for (((_, columns), label), layout) in table
+            .column_groups
+            .iter()
+            .zip(&self.groups)
+            .zip(groups.children())
+        {
+            let viewport = if *columns.start() < table.frozen_columns {
+                header_viewport
+            } else {
+                self.scrolled_viewport(header_viewport)
+            };
+
+            let group = layout
+                .children()
+                .next()
+                .expect("Table draw: Resize node missing group layout");
+
+            if let Some(clipped_viewport) = layout.bounds().intersection(&viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        ..Default::default()
+                    },
+                    style.grid_line,
+                );
+            }
+
+            let grid = self.grid_bounds(group.bounds(), line_width, true, true);
+
+            if let Some(clipped_viewport) = grid.intersection(&viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped_viewport,
+                        ..Default::default()
+                    },
+                    style.group_header_background,
+                );
+            }
+
+            if let Some(clipped_viewport) = group.bounds().intersection(&viewport) {
+                draw(
+                    renderer,
+                    style.group_header_text,
+                    group,
+                    label.raw(),
+                    padding,
+                    &clipped_viewport,
+                )
+            }
+        }
+
+        if let Some(size) = top_left {
+            let bounds = Rectangle::new(layout.position(), size);
+            let background = if cursor.is_over(Self::corner_bounds(layout)) {
+                style.hovered_corner_background
+            } else {
+                style.header_background
+            };
+
+            if let Some(clipped) = bounds.intersection(&og_viewport) {
+                <Renderer as advanced::Renderer>::fill_quad(
+                    renderer,
+                    Quad {
+                        bounds: clipped,
+                        ..Default::default()
+                    },
+                    background,
+                );
+            }
+        }
+
+        match (editing, &self.editing) {
+            (
+                Some(bounds),
+                Some(Editing::Cell {
+                    index,
+                    value,
+                    is_header: true,
+                    ..
+                }),
+            ) => {
+                let (cell, _) = &self.headers[*index];
+                let header_viewport = if *index < table.frozen_columns {
+                    header_viewport
+                } else {
+                    self.scrolled_viewport(header_viewport)
+                };
+
+                if let Some(clipped_bounds) = header_viewport.intersection(&bounds) {
+                    self.draw_edit(
+                        renderer,
+                        style,
+                        cell,
+                        clipped_bounds,
+                        bounds,
+                        value,
+                        cell.horizontal_alignment(),
+                    )
+                }
+            }
+            (
+                Some(bounds),
+                Some(Editing::Cell {
+                    index,
+                    value,
+                    is_header: false,
+                    ..
+                }),
+            ) => {
+                let cell = &self.cells[*index];
+                let cell_viewport = if *index / table.page_limit < table.frozen_columns {
+                    cell_viewport
+                } else {
+                    self.scrolled_viewport(cell_viewport)
+                };
+
+                if let Some(clipped_bounds) = cell_viewport.intersection(&bounds) {
+                    self.draw_edit(
+                        renderer,
+                        style,
+                        cell,
+                        clipped_bounds,
+                        bounds,
+                        value,
+                        cell.horizontal_alignment(),
+                    )
+                }
+            }
+            _ => {}
+        };
+
+        if let Some((idx, full)) = self
+            .overflow_hover
+            .as_ref()
+            .filter(|_| self.editing.is_none() && self.motion.is_none())
+        {
+            let child = cells
+                .children()
+                .nth(*idx)
+                .and_then(|layout| layout.children().next());
+
+            if let Some(child) = child {
+                let bounds = child.bounds();
+                let bounds = Rectangle::new(
+                    bounds.position(),
+                    Size::new(
+                        bounds.width.max(full.min_bounds().expand(padding).width),
+                        bounds.height,
+                    ),
+                );
+
+                let (background, text_color) = if (idx % table.page_limit).is_multiple_of(2) {
+                    (
+                        style.alternating_backgrounds.1,
+                        style.alternating_text_color.1,
+                    )
+                } else {
+                    (
+                        style.alternating_backgrounds.0,
+                        style.alternating_text_color.0,
+                    )
+                };
+
+                // Drawn in its own layer to cover the text of neighbouring cells
+                <Renderer as advanced::Renderer>::with_layer(renderer, cell_viewport, |renderer| {
+                    <Renderer as advanced::Renderer>::fill_quad(
+                        renderer,
+                        Quad {
+                            bounds,
+                            border: Border::default().width(1.0).color(style.overflow_border),
+                            ..Default::default()
+                        },
+                        background,
+                    );
+
+                    renderer.fill_paragraph(
+                        full.raw(),
+                        Point::new(bounds.x + padding.left, bounds.center_y()),
+                        text_color,
+                        cell_viewport,
+                    );
+                });
+            }
+        }
+    }
+
+    /// Starts waiting for the tooltip of the data cell under the cursor.
+    fn hover_tooltip<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_busy = self.editing.is_some()
+            || self.resizing.is_some()
+            || self.motion.is_some()
+            || self.selection_drag.is_some()
+            || self.scrollbar_drag.is_some();
+
+        if is_busy {
+            return;
+        }
+
+        let mut children = layout.children().skip(1);
+        let headers = children.next();
+        let cells = children.next();
+
+        let kind = headers.and_then(|headers| {
+            headers.children().position(|resize| {
+                resize
+                    .children()
+                    .next()
+                    .and_then(|pair| pair.children().nth(1))
+                    .is_some_and(|kind| cursor.is_over(kind.bounds()))
+            })
+        });
+
+        let cell = cells
+            .filter(|_| table.on_tooltip.is_some())
+            .and_then(|cells| {
+                cells
+                    .children()
+                    .position(|cell| cursor.is_over(cell.bounds()))
+            })
+            .map(|idx| {
+                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                (row + (self.page * table.page_limit), column)
+            })
+            .filter(|(row, _)| *row < table.rows)
+            .map(|(row, column)| TooltipTarget::Cell { row, column });
+
+        if let Some(target) = kind.map(TooltipTarget::Kind).or(cell) {
+            let now = Instant::now();
+            self.tooltip_hover = Some((target, now));
+            shell.request_redraw(window::RedrawRequest::At(now + TOOLTIP_DELAY));
+        }
+    }
+
+    /// Publishes an [`Action::Hover`] when the data cell under the cursor
+    /// changes.
+    fn hover_cell<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        viewport: Rectangle,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let is_dragging = self.resizing.is_some()
+            || self.is_text_dragging
+            || self.motion.is_some()
+            || self.selection_drag.is_some()
+            || self.scrollbar_drag.is_some();
+
+        if is_dragging {
+            return;
+        }
+
+        let hovered = cursor
+            .position_over(viewport)
+            .and_then(|_| layout.children().nth(2))
+            .and_then(|cells| {
+                cells
+                    .children()
+                    .position(|cell| cursor.is_over(cell.bounds()))
+            })
+            .map(|idx| {
+                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                (row + (self.page * table.page_limit), column)
+            })
+            .filter(|(row, _)| *row < table.rows);
+
+        if hovered == self.hovered_cell {
+            return;
+        }
+
+        self.hovered_cell = hovered;
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            shell.publish(on_action(Action::Hover(hovered)));
+        }
+    }
+
+    /// Shows the tooltip of the hovered cell once the cursor has rested long
+    /// enough.
+    fn show_tooltip<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        cursor: mouse::Cursor,
+        now: Instant,
+    ) {
+        let Some((target, since)) = self.tooltip_hover else {
+            return;
+        };
+
+        if now < since + TOOLTIP_DELAY {
+            return;
+        }
+
+        self.tooltip_hover = None;
+
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let tooltip = match target {
+            TooltipTarget::Cell { row, column } => table
+                .on_tooltip
+                .as_ref()
+                .and_then(|on_tooltip| on_tooltip(row, column)),
+            TooltipTarget::Kind(column) => table
+                .raw
+                .column_kind(column)
+                .and_then(|kind| table.raw.kind_description(&kind)),
+        };
+
+        if let Some(tooltip) = tooltip {
+            let font = table.font.unwrap_or_else(|| renderer.default_font());
+            let size = table.text_size.unwrap_or_else(|| renderer.default_size());
+
+            let tooltip = Cell::<Renderer>::new(super::text::<Renderer>(
+                &tooltip,
+                self.max_cell,
+                font,
+                Horizontal::Left,
+                size * Self::KIND_MULT,
+                self.shaping,
+                self.line_height,
+            ));
+
+            self.tooltip = Some((position, tooltip));
+        }
+    }
+
+    /// Dims the cells `viewport`, centering the [`LOADING`] paragraph over it.
+    fn draw_loading(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        style: Style,
+        viewport: &Rectangle,
+    ) {
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        <Renderer as advanced::Renderer>::with_layer(renderer, clip, |renderer| {
+            <Renderer as advanced::Renderer>::fill_quad(
+                renderer,
+                Quad {
+                    bounds,
+                    ..Default::default()
+                },
+                style.loading_background,
+            );
+
+            renderer.fill_paragraph(
+                self.loading.raw(),
+                bounds.center(),
+                style.loading_text,
+                clip,
+            );
+        });
+    }
+
+    fn draw_tooltip(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        style: Style,
+        viewport: &Rectangle,
+        padding: Padding,
+    ) {
+        let Some((position, tooltip)) = self.tooltip.as_ref() else {
+            return;
+        };
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let size = tooltip.min_bounds().expand(padding);
+        let position = *position + Vector::new(12.0, 16.0);
+
+        // Kept within the table
+        let x = position
+            .x
+            .min(bounds.x + bounds.width - size.width)
+            .max(bounds.x);
+        let y = position
+            .y
+            .min(bounds.y + bounds.height - size.height)
+            .max(bounds.y);
+        let tooltip_bounds = Rectangle::new(Point::new(x, y), size);
+
+        <Renderer as advanced::Renderer>::with_layer(renderer, clip, |renderer| {
+            <Renderer as advanced::Renderer>::fill_quad(
+                renderer,
+                Quad {
+                    bounds: tooltip_bounds,
+                    border: style.tooltip_border,
+                    ..Default::default()
+                },
+                style.tooltip_background,
+            );
+
+            renderer.fill_paragraph(
+                tooltip.raw(),
+                Point::new(x + padding.left, tooltip_bounds.center_y()),
+                style.tooltip_text,
+                clip,
+            );
+        });
+    }
+
+    /// Tracks the truncated cell under the cursor, laying out its full text.
+    fn hover_overflow<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        font: Renderer::Font,
+        size: Pixels,
+    ) {
+        let hovered = layout
+            .children()
+            .nth(2)
+            .and_then(|cells| {
+                cells
+                    .children()
+                    .position(|cell| cursor.is_over(cell.bounds()))
+            })
+            .filter(|idx| self.truncated.contains(idx));
+
+        match hovered {
+            Some(idx)
+                if self
+                    .overflow_hover
+                    .as_ref()
+                    .is_some_and(|(current, _)| *current == idx) => {}
+            Some(idx) => {
+                let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                let value = table.display_cell(row + (self.page * table.page_limit), column);
+
+                let full = Cell::<Renderer>::new(super::text::<Renderer>(
+                    &value,
+                    self.max_cell,
+                    font,
+                    Horizontal::Left,
+                    size,
+                    self.shaping,
+                    self.line_height,
+                ));
+
+                self.overflow_hover = Some((idx, full));
+            }
+            None => self.overflow_hover = None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_edit(
+        &self,
+        renderer: &mut Renderer,
+        style: Style,
+        cell: &Cell<Renderer>,
+        clipped_bounds: Rectangle,
+        full_bounds: Rectangle,
+        value: &str,
+        alignment: Horizontal,
+    ) {
+        let (quads, color, offset, is_selecting) = if let Some(focus) = self
+            .is_focused
+            .as_ref()
+            .filter(|focus| focus.is_window_focused)
+        {
+            let min_bounds = cell.min_bounds();
+            let text_top =
+                full_bounds.y + ((full_bounds.height - min_bounds.height).max(0.0) * 0.5);
+
+            // Clips a line of the text to the visible bounds
+            let line = |x: f32, width: f32, caret: Rectangle| {
+                let y = text_top + caret.y;
+                let y2 = y + caret.height;
+                let y = y.max(clipped_bounds.y);
+                let height = (y2 - y).max(0.0);
+
+                Quad {
+                    bounds: Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                    },
+                    ..Quad::default()
+                }
+            };
+
+            match self.cursor.state(value) {
+                utils::State::Index(position) => {
+                    let (caret, offset) = measure_cursor_and_scroll_offset(
+                        cell.raw(),
+                        value,
+                        clipped_bounds,
+                        position,
+                    );
+
+                    let is_cursor_visible = !self.cursor_blink
+                        || ((focus.now - focus.updated_at).as_millis()
+                            / CURSOR_BLINK_INTERVAL_MILLIS)
+                            .is_multiple_of(2);
+
+                    let quads = if is_cursor_visible {
+                        vec![line((clipped_bounds.x + caret.x).floor(), 1.0, caret)]
+                    } else {
+                        vec![]
+                    };
+
+                    (quads, style.cursor_color, offset, false)
+                }
+                utils::State::Selection { start, end } => {
+                    let left = start.min(end);
+                    let right = end.max(start);
+
+                    let (left_caret, left_offset) =
+                        measure_cursor_and_scroll_offset(cell.raw(), value, clipped_bounds, left);
+
+                    let (right_caret, right_offset) =
+                        measure_cursor_and_scroll_offset(cell.raw(), value, clipped_bounds, right);
+
+                    let paragraph = cell.raw();
+                    let first = (left_caret.y / left_caret.height.max(1.0)).round() as usize;
+                    let last = (right_caret.y / right_caret.height.max(1.0)).round() as usize;
+
+                    // Selections spanning wrapped lines are highlighted line by line
+                    let quads = (first..=last)
+                        .map(|idx| {
+                            let start = if idx == first {
+                                left_caret.x
+                            } else {
+                                paragraph
+                                    .grapheme_position(idx, 0)
+                                    .map(|point| point.x)
+                                    .unwrap_or_default()
+                            };
+                            let end = if idx == last {
+                                right_caret.x
+                            } else {
+                                paragraph
+                                    .grapheme_position(idx, usize::MAX)
+                                    .map(|point| point.x)
+                                    .unwrap_or(start)
+                            };
+                            let caret = Rectangle {
+                                y: idx as f32 * left_caret.height,
+                                ..left_caret
+                            };
+
+                            line(clipped_bounds.x + start, end - start, caret)
+                        })
+                        .collect();
+
+                    (
+                        quads,
+                        style.cursor_selection,
+                        if end == right {
+                            right_offset
+                        } else {
+                            left_offset
+                        },
+                        true,
+                    )
+                }
+            }
+        } else {
+            (vec![], Color::TRANSPARENT, 0.0, false)
+        };
+
+        let draw = |renderer: &mut Renderer| {
+            let paragraph = cell.raw();
+
+            let alignment_offset =
+                alignment_offset(clipped_bounds.width, paragraph.min_width(), alignment);
+
+            if quads.is_empty() {
+                <Renderer as advanced::Renderer>::with_translation(renderer, Vector::ZERO, |_| {});
+            } else {
+                <Renderer as advanced::Renderer>::with_translation(
+                    renderer,
+                    Vector::new(alignment_offset - offset, 0.0),
+                    |renderer| {
+                        for quad in &quads {
+                            <Renderer as advanced::Renderer>::fill_quad(renderer, *quad, color);
+                        }
+                    },
+                );
+            }
+        };
+
+        if is_selecting {
+            <Renderer as advanced::Renderer>::with_layer(renderer, clipped_bounds, |renderer| {
+                draw(renderer)
+            });
+        } else {
+            draw(renderer);
+        }
+    }
+
+    pub fn draw<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &mut Renderer,
+        layout: layout::Layout<'_>,
+        style: Style,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let padding = table.padding;
+        // Nothing is hovered while loading
+        let cursor = if table.loading {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let cells = children.next().expect("Widget draw: Missing cells layout");
+        let status = children.next().expect("Widget draw: Missing status layout");
+        let pagination = children
+            .next()
+            .expect("Widget draw: Missing pagination layout");
+        let goto = children.next().expect("Widget draw: Missing goto layout");
+        let sizes = children
+            .next()
+            .expect("Widget draw: Missing page sizes layout");
+        let footer = children.next().expect("Widget draw: Missing footer layout");
+        let search = children.next().expect("Widget draw: Missing search layout");
+
+        let cells_bounds = {
+            let area = self.cells_area(bounds, padding, cells);
+
+            Rectangle::new(area.position(), area.size() + Size::new(self.cell_gap, 0.0))
+        };
+
+        if let Some(clipped_viewport) = cells_bounds.intersection(viewport) {
+            self.draw_cells(
+                table,
+                renderer,
+                cells,
+                style,
+                cursor,
+                clipped_viewport,
+                table.cell_padding,
+            )
+        };
+
+        let cells_viewport = self.cells_viewport(bounds, padding, cells);
+
+        self.draw_scrollbars(table, renderer, cells_viewport, style, cursor, viewport);
+
+        if table.loading {
+            self.draw_loading(renderer, cells_viewport, style, viewport);
+        }
+
+        self.draw_footer(
+            renderer,
+            footer,
+            style,
+            cells_viewport,
+            viewport,
+            table.cell_padding,
+            table.frozen_columns,
+        );
+
+        self.draw_status(renderer, status, style, viewport);
+
+        if table.searchable {
+            self.draw_search(renderer, search, style, viewport);
+        }
+
+        if table.multiple_pages() {
+            self.draw_pagination(table, renderer, pagination, style, cursor, viewport);
+
+            self.draw_goto(renderer, goto, style, cursor, viewport);
+        }
+
+        if table.shows_page_sizes() {
+            self.draw_page_sizes(table, renderer, sizes, style, cursor, viewport);
+        }
+
+        if let Some(Editing::Goto(bounds) | Editing::Search(bounds)) = &self.editing {
+            let (cell, value) = self.input(matches!(self.editing, Some(Editing::Search(_))));
+
+            self.draw_edit(
+                renderer,
+                style,
+                cell,
+                *bounds,
+                *bounds,
+                value,
+                cell.horizontal_alignment(),
+            )
+        };
+
+        self.draw_tooltip(renderer, bounds, style, viewport, table.cell_padding);
+    }
+
+    fn interaction_cells(
+        &self,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(Self::corner_bounds(layout)) {
+            return mouse::Interaction::Pointer;
+        }
+
+        let mut children = layout.children();
+        let _numbering = children
+            .next()
+            .expect("Widget Interaction: Missing numbering cells");
+        let headers = children
+            .next()
+            .expect("Widget Interaction: Missing header cells");
+
+        for (idx, resize) in headers.children().enumerate() {
+            let pair = resize
+                .children()
+                .next()
+                .expect("Table Interaction: Resize node missing pair layout");
+
+            let resize = resize.bounds();
+
+            let label = pair
+                .children()
+                .next()
+                .expect("Table Interaction: Pair node missing label layout")
+                .bounds();
+            let kind = pair.children().nth(1).map(|kind| kind.bounds());
+
+            let pair = pair.bounds();
+
+            match &self.editing {
+                Some(Editing::Cell {
+                    index,
+                    is_header: true,
+                    ..
+                }) if *index == idx && cursor.is_over(label) => {
+                    return mouse::Interaction::Text;
+                }
+                _ if self.sort_width > 0.0 && cursor.is_over(self.sort_bounds(label)) => {
+                    return mouse::Interaction::Pointer;
+                }
+                _ if kind.is_some_and(|kind| cursor.is_over(kind)) => {
+                    return mouse::Interaction::Pointer;
+                }
+                _ if cursor.is_over(pair) => {
+                    return mouse::Interaction::Cell;
+                }
+                _ if cursor.is_over(resize) => {
+                    let horizontal = {
+                        let position = resize.position() + Vector::new(pair.width, 0.0);
+                        let height = resize.height;
+                        let width = resize.width - pair.width;
+
+                        let horizontal = Rectangle::new(position, Size::new(width, height));
+
+                        cursor.is_over(horizontal)
+                    };
+                    let vertical = {
+                        let position = resize.position() + Vector::new(0.0, pair.height);
+                        let width = resize.width;
+                        let height = resize.height - pair.height;
+
+                        let vertical = Rectangle::new(position, Size::new(width, height));
+                        cursor.is_over(vertical)
+                    };
+
+                    if vertical && horizontal {
+                        return mouse::Interaction::ResizingDiagonallyDown;
+                    }
+
+                    if vertical {
+                        return mouse::Interaction::ResizingVertically;
+                    }
+
+                    if horizontal {
+                        return mouse::Interaction::ResizingHorizontally;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let cells = children.next().expect("Widget Interaction: Missing cells");
+
+        for (idx, cell) in cells.children().enumerate() {
+            let resize = cell.bounds();
+            let child = cell
+                .children()
+                .next()
+                .expect("Table Interaction: Resize node missing child layout")
+                .bounds();
+
+            match &self.editing {
+                Some(Editing::Cell {
+                    index,
+                    is_header: false,
+                    ..
+                }) if *index == idx && cursor.is_over(child) => {
+                    return mouse::Interaction::Text;
+                }
+                _ if cursor.is_over(child) => {
+                    return mouse::Interaction::Cell;
+                }
+                _ if cursor.is_over(resize) => {
+                    let horizontal = {
+                        let position = resize.position() + Vector::new(child.width, 0.0);
+                        let height = resize.height;
+                        let width = resize.width - child.width;
+
+                        let horizontal = Rectangle::new(position, Size::new(width, height));
+
+                        cursor.is_over(horizontal)
+                    };
+                    let vertical = {
+                        let position = resize.position() + Vector::new(0.0, child.height);
+                        let width = resize.width;
+                        let height = resize.height - child.height;
+
+                        let vertical = Rectangle::new(position, Size::new(width, height));
+                        cursor.is_over(vertical)
+                    };
+
+                    if vertical && horizontal {
+                        return mouse::Interaction::ResizingDiagonallyDown;
+                    }
+
+                    if vertical {
+                        return mouse::Interaction::ResizingVertically;
+                    }
+
+                    if horizontal {
+                        return mouse::Interaction::ResizingHorizontally;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn interaction_pagination<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+
+        let back = children
+            .next()
+            .expect("Widget Interaction: missing paginations: Back");
+
+        if cursor.is_over(back.bounds()) && self.page != 0 {
+            return mouse::Interaction::Pointer;
+        }
+
+        let pages = children
+            .next()
+            .expect("Widget Interaction: missing paginations: Pages");
+
+        let is_over_page = pages
+            .children()
+            .enumerate()
+            .any(|(idx, page)| cursor.is_over(page.bounds()) && !self.is_inert_page(table, idx));
+
+        if is_over_page {
+            return mouse::Interaction::Pointer;
+        }
+
+        let next = children
+            .next()
+            .expect("Widget Interaction: missing paginations: Next");
+
+        if cursor.is_over(next.bounds()) && self.page != table.pages_end() {
+            return mouse::Interaction::Pointer;
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn interaction_goto(
+        &self,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let _ = children.next();
+
+        let input = children
+            .next()
+            .expect("Widget interaction: Missing goto input layout");
+
+        if cursor.is_over(input.bounds()) {
+            return mouse::Interaction::Text;
+        }
+
+        let go = children
+            .next()
+            .expect("Widget Interaction: Missing goto go layout");
+        if cursor.is_over(go.bounds()) {
+            return mouse::Interaction::Pointer;
+        }
+
+        mouse::Interaction::None
+    }
+
+    pub fn mouse_interaction<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if table.loading {
+            return mouse::Interaction::default();
+        }
+
+        if let Some(interaction) = self.resizing.map(|resize| resize.interaction()) {
+            return interaction;
+        }
+
+        if self.motion.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let mut children = layout.children();
+
+        let cells = children
+            .next()
+            .expect("Widget Interaction: Missing cells layout");
+        if cursor.is_over(cells.bounds()) {
+            return self.interaction_cells(cells, cursor);
+        }
+
+        let _status = children.next();
+
+        let pagination = children
+            .next()
+            .expect("Widget Interaction: Missing pagination layout");
+        let goto = children
+            .next()
+            .expect("Widget Interaction: Missing goto layout");
+        let sizes = children
+            .next()
+            .expect("Widget Interaction: Missing page sizes layout");
+
+        if table.multiple_pages() {
+            if cursor.is_over(pagination.bounds()) {
+                return self.interaction_pagination(table, pagination, cursor);
+            }
+
+            if cursor.is_over(goto.bounds()) {
+                return self.interaction_goto(goto, cursor);
+            }
+        }
+
+        let is_over_size = sizes
+            .children()
+            .nth(1)
+            .is_some_and(|sizes| sizes.children().any(|size| cursor.is_over(size.bounds())));
+
+        if table.shows_page_sizes() && is_over_size {
+            return mouse::Interaction::Pointer;
+        }
+
+        mouse::Interaction::None
+    }
+
+    /// Selects the header, row number or data cell under the cursor unless it
+    /// already is, and publishes an [`Action::RightClick`] on it.
+    fn update_cells_right_click<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(position) = cursor.position() else {
+            return event::Status::Ignored;
+        };
+
+        if cursor.is_over(Self::corner_bounds(layout)) {
+            return event::Status::Captured;
+        }
+
+        let mut children = layout.children();
+        let numbering = children
+            .next()
+            .expect("Widget Update: Missing numbering cells");
+        let headers = children
+            .next()
+            .expect("Widget Update: Missing header cells");
+        let cells = children.next().expect("Widget Update: Missing cells");
+
+        let page_start = self.page * table.page_limit;
+        let is_over = |child: layout::Layout<'_>| cursor.is_over(child.bounds());
+
+        let target = if let Some(idx) = numbering.children().skip(1).position(is_over) {
+            CellTarget {
+                row: page_start + idx,
+                column: 0,
+                kind: TargetKind::Numbering,
+            }
+        } else if let Some(column) = headers.children().position(is_over) {
+            CellTarget {
+                row: 0,
+                column,
+                kind: TargetKind::Header,
+            }
+        } else if let Some(idx) = cells.children().position(is_over) {
+            CellTarget {
+                row: page_start + (idx % table.page_limit),
+                column: idx / table.page_limit,
+                kind: TargetKind::Cell,
+            }
+        } else {
+            return event::Status::Ignored;
+        };
+
+        if target.row >= table.rows {
+            return event::Status::Ignored;
+        }
+
+        let is_selected = self
+            .selection
+            .as_ref()
+            .is_some_and(|selection| match target.kind {
+                TargetKind::Header => selection.header(target.column),
+                TargetKind::Numbering | TargetKind::Cell => {
+                    selection.contains(target.row, target.column)
+                }
+            });
+
+        if !is_selected {
+            let selection = match target.kind {
+                TargetKind::Header => Selection::column(target.column, self.page_rows(table)),
+                TargetKind::Numbering => Selection::row(target.row, table.cols.saturating_sub(1)),
+                TargetKind::Cell => Selection::new(target.row, target.column),
+            };
+
+            self.reset_editing();
+            self.motion = None;
+            self.selection = Some(selection.clone());
+
+            if let Some(on_action) = table.on_action.as_ref() {
+                let msg = on_action(Action::Selection(selection));
+                shell.publish(msg);
+            }
+        }
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            let msg = on_action(Action::RightClick { target, position });
+            shell.publish(msg);
+        }
+
+        event::Status::Captured
+    }
+
+    fn update_cells_click<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let padding = table.cell_padding;
+
+        if cursor.is_over(Self::corner_bounds(layout)) {
+            // Empty tables have no cells to select
+            if table.rows == 0 || table.cols == 0 {
+                return event::Status::Captured;
+            }
+
+            let selection =
+                Selection::row_range(self.page_rows(table), table.cols.saturating_sub(1));
+
+            if let Some(on_action) = table.on_action.as_ref() {
+                let action = Action::Selection(selection.clone());
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
+
+            self.reset_editing();
+            self.selection = Some(selection);
+            self.motion = None;
+            return event::Status::Captured;
+        }
+
+        let groups = layout
+            .children()
+            .nth(3)
+            .expect("Widget Update: Missing column groups");
+
+        // Group headers select all of their columns on the page
+        if let Some((_, columns)) = groups
+            .children()
+            .position(|group| cursor.is_over(group.bounds()))
+            .and_then(|idx| table.column_groups.get(idx))
+        {
+            let end = (*columns.end()).min(table.cols.saturating_sub(1));
+            let selection = Selection::column_range(*columns.start()..=end, self.page_rows(table));
+
+            if let Some(on_action) = table.on_action.as_ref() {
+                let action = Action::Selection(selection.clone());
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
+
+            self.reset_editing();
+            self.selection = Some(selection);
+            self.motion = None;
+            return event::Status::Captured;
+        }
+
+        let mut children = layout.children();
+        let numbering = children
+            .next()
+            .expect("Widget Update: Missing numbering cells");
+
+        if let Some((idx, numbering)) = numbering
+            .children()
+            .enumerate()
+            .filter(|(idx, _)| *idx != 0)
+            .find(|(_, child)| cursor.is_over(child.bounds()))
+        {
+            let row = idx - 1;
+            let row = row + (self.page * table.page_limit);
+            let bounds = numbering.bounds();
+            // Guaranteed by the find above
+            let cursor_position = cursor.position_over(bounds).unwrap();
+            let click = mouse::Click::new(cursor_position, mouse::Button::Left, self.last_click);
+
+            self.last_click = Some(click);
+            self.reset_editing();
+
+            if matches!(click.kind(), click::Kind::Double) {
+                if let Some(on_action) = table.on_action.as_ref() {
+                    let action = Action::DoubleClick(CellTarget {
+                        row,
+                        column: 0,
+                        kind: TargetKind::Numbering,
+                    });
+                    shell.publish(on_action(action));
+                }
+            }
+
+            let column_end = table.cols.saturating_sub(1);
+            let bounds = self
+                .selection
+                .as_ref()
+                .and_then(|selection| selection.bounds());
+
+            let selection = match (self.selection.take(), bounds) {
+                (Some(_), Some((rows, _))) if self.keyboard_modifiers.shift() => {
+                    let start = *rows.start().min(&row);
+                    let end = *rows.end().max(&row);
+
+                    Selection::row_range(start..=end, column_end)
+                }
+                (Some(mut selection), _) if self.keyboard_modifiers.command() => {
+                    for column in 0..=column_end {
+                        selection.scattered(row, column);
+                    }
+
+                    selection
+                }
+                _ => Selection::row(row, column_end),
+            };
+            self.selection.replace(selection);
+
+            if let Some(on_action) = table.on_action.as_ref() {
+                // Guaranteed by the replace above
+                let action = Action::Selection(self.selection.clone().unwrap());
+                let msg = on_action(action);
+                shell.publish(msg);
+            }
+            if self.editing.is_none() && !table.read_only {
+                self.motion = self
+                    .selection
+                    .as_ref()
+                    .and_then(|selection| selection.motion());
+            }
+            return event::Status::Captured;
+        }
+
+        let headers = children
+            .next()
+            .expect("Widget Update: Missing header cells")
+            .children()
+            .map(|child| (true, child));
+        let cells = children
+            .next()
+            .expect("Widget Update: Missing cells")
+            .children()
+            .map(|child| (false, child));
+        let children = headers.chain(cells);
+
+        match children
+            .enumerate()
+            .find(|(_, (_, child))| cursor.is_over(child.bounds()))
+        {
+            Some((idx, (is_header, cell))) => {
+                let cell_bounds = cell.bounds();
+                let cell = cell
+                    .children()
+                    .next()
+                    .expect("Table Update: Resize node missing child layout");
+
+                let cursor_position = cursor.position_over(cell.bounds());
+
+                let (row, column) = if is_header {
+                    (0, idx + 1)
+                } else {
+                    let idx = idx - table.cols;
+                    let column = (idx / table.page_limit) + 1;
+                    let row = (idx + 1) - ((idx / table.page_limit) * table.page_limit);
+                    (row, column)
+                };
+
+                let resize = Resizing::new(cell_bounds, cell.bounds(), cursor, row, column);
+
+                if let Some(resize) = resize {
+                    self.reset_editing();
+
+                    let click = cursor.position().map(|position| {
+                        mouse::Click::new(position, mouse::Button::Left, self.last_click)
+                    });
+
+                    // Double clicking a handle fits the column and/or row to
+                    // its contents again
+                    if click.is_some_and(|click| matches!(click.kind(), click::Kind::Double)) {
+                        self.last_click = None;
+                        self.auto_fit(resize);
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+
+                    self.last_click = click;
+                    self.resizing = Some(resize);
+                    return event::Status::Captured;
+                }
+
+                if is_header && table.sortable {
+                    let label = cell
+                        .children()
+                        .next()
+                        .expect("Table Update: Pair node missing label layout")
+                        .bounds();
+
+                    let is_over_label = cursor.is_over(label) && self.keyboard_modifiers.alt();
+
+                    if is_over_label || cursor.is_over(self.sort_bounds(label)) {
+                        self.sort(table, idx, shell);
+                        return event::Status::Captured;
+                    }
+                }
+
+                // The kind under a header is only clicked, never selected
+                if is_header
+                    && cell
+                        .children()
+                        .nth(1)
+                        .is_some_and(|kind| cursor.is_over(kind.bounds()))
+                {
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        shell.publish(on_action(Action::KindClick(idx)));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                let Some(cursor_position) = cursor_position else {
+                    return event::Status::Ignored;
+                };
+
+                let click =
+                    mouse::Click::new(cursor_position, mouse::Button::Left, self.last_click);
+
+                let (row, column) = if is_header {
+                    (0, idx)
+                } else {
+                    let idx = idx - table.cols;
+                    let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                    let row = row + (self.page * table.page_limit);
+                    (row, column)
+                };
+
+                let cell_bounds = cell.bounds().shrink(padding);
+
+                let Some(cursor_position) = cursor.position_over(cell_bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let (idx, cell, value) = if is_header {
+                    let (cell, _) = &self.headers[idx];
+
+                    let value = table.raw.column_header(idx).unwrap_or_default();
+
+                    (idx, cell, value)
+                } else {
+                    let idx = idx - table.cols;
+                    let cell = &self.cells[idx];
+                    let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                    let row = row + (self.page * table.page_limit);
+
+                    let value = table
+                        .raw
+                        .cell(row, column)
+                        .map(Cow::into_owned)
+                        .unwrap_or_default();
+
+                    (idx, cell, value)
+                };
+
+                let target = {
+                    let alignment_offset = alignment_offset(
+                        cell_bounds.width,
+                        cell.min_width(),
+                        cell.horizontal_alignment(),
+                    );
+
+                    cursor_position.x - cell_bounds.x - alignment_offset
+                };
+
+                let (editing_idx, editing_is_header) = match self.editing.as_ref() {
+                    Some(Editing::Cell {
+                        index, is_header, ..
+                    }) => (Some(*index), *is_header),
+                    _ => (None, false),
+                };
+
+                let is_editing_target = editing_idx == Some(idx) && is_header == editing_is_header;
+
+                if matches!(click.kind(), click::Kind::Double) && !is_editing_target {
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let kind = if is_header {
+                            TargetKind::Header
+                        } else {
+                            TargetKind::Cell
+                        };
+                        let action = Action::DoubleClick(CellTarget { row, column, kind });
+                        shell.publish(on_action(action));
+                    }
+                }
+
+                // Locked cells never start editing, so every click selects
+                let kind = if !table.is_editable(is_header, column)
+                    || (!table.double_click_edits && !is_editing_target)
+                {
+                    click::Kind::Single
+                } else {
+                    click.kind()
+                };
+
+                match kind {
+                    click::Kind::Single if self.keyboard_modifiers.shift() && !is_header => {
+                        self.last_click = Some(click);
+                        let Some(selection) = self.selection.as_mut() else {
+                            return event::Status::Ignored;
+                        };
+
+                        selection.block(row, column);
+
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            let action = Action::Selection(selection.clone());
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+
+                        self.reset_editing();
+                    }
+                    click::Kind::Single if self.keyboard_modifiers.command() && !is_header => {
+                        self.last_click = Some(click);
+                        let Some(selection) = self.selection.as_mut() else {
+                            return event::Status::Ignored;
+                        };
+                        selection.toggle(row, column);
+
+                        // The last toggled off cell stays active, so the
+                        // emptied selection is never published
+                        let action = if selection.is_empty() {
+                            self.reset_selection();
+                            Action::SelectionClear
+                        } else {
+                            Action::Selection(selection.clone())
+                        };
+
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+
+                        self.reset_editing();
+                    }
+                    click::Kind::Single
+                        if editing_idx.is_some()
+                            && editing_idx.unwrap() == idx
+                            && is_header == editing_is_header =>
+                    {
+                        // Needs to be in sync with kind::Double
+                        let position = if target > 0.0 {
+                            find_cursor_position(
+                                cell_bounds,
+                                &value,
+                                self,
+                                cell,
+                                target,
+                                cursor_position.y - cell_bounds.y,
+                            )
+                        } else {
+                            None
+                        }
+                        .unwrap_or(0);
+
+                        if self.keyboard_modifiers.shift() {
+                            self.cursor
+                                .select_range(self.cursor.start(&value), position);
+                        } else {
+                            self.cursor.move_to(position);
+                        }
+
+                        self.is_text_dragging = true;
+
+                        self.last_click = Some(click);
+                        self.editing = Some(Editing::Cell {
+                            index: idx,
+                            value,
+                            is_header,
+                            history: History::default(),
+                        });
+                    }
+                    click::Kind::Single if is_header => {
+                        self.last_click = Some(click);
+                        self.reset_editing();
+                        let page_rows = self.page_rows(table);
+                        let bounds = self
+                            .selection
+                            .as_ref()
+                            .and_then(|selection| selection.bounds());
+
+                        let selection = match (self.selection.take(), bounds) {
+                            (Some(_), Some((_, columns))) if self.keyboard_modifiers.shift() => {
+                                let start = *columns.start().min(&column);
+                                let end = *columns.end().max(&column);
+
+                                Selection::column_range(start..=end, page_rows)
+                            }
+                            (Some(mut selection), _) if self.keyboard_modifiers.command() => {
+                                for row in page_rows {
+                                    selection.scattered(row, column);
+                                }
+
+                                selection
+                            }
+                            _ => Selection::column(column, page_rows),
+                        };
+                        self.selection.replace(selection);
+
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            // Guaranteed by the replace above
+                            let action = Action::Selection(self.selection.clone().unwrap());
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+                    }
+                    click::Kind::Single => {
+                        self.last_click = Some(click);
+                        match editing_idx {
+                            Some(index) if is_header == editing_is_header && index == idx => {}
+                            _ => self.reset_editing(),
+                        }
+
+                        // Dragging an already selected cell moves it, otherwise
+                        // a new selection is dragged out
+                        let is_selected = self
+                            .selection
+                            .as_ref()
+                            .is_some_and(|selection| selection.contains(row, column));
+                        if !is_selected && self.editing.is_none() {
+                            self.selection_drag = Some((row, column));
+                        }
+
+                        self.selection.replace(Selection::new(row, column));
+
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            // Guaranteed by the Selection::new above
+                            let action = Action::Selection(self.selection.clone().unwrap());
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+
+                        if !is_header && row < table.rows && table.is_toggleable(column) {
+                            self.selection_drag = None;
+                            self.toggle_cell(table, row, column, shell);
+                        }
+                    }
+                    click::Kind::Double if self.editing.is_some() => {
+                        let position = find_cursor_position(
+                            cell_bounds,
+                            &value,
+                            self,
+                            cell,
+                            target,
+                            cursor_position.y - cell_bounds.y,
+                        )
+                        .unwrap_or(0);
+                        let (start, end) = word_boundary(&value, position);
+                        self.cursor.select_range(start, end);
+                        self.is_text_dragging = false;
+
+                        self.last_click = Some(click);
+                        self.editing = Some(Editing::Cell {
+                            index: idx,
+                            value,
+                            is_header,
+                            history: History::default(),
+                        });
+                    }
+                    click::Kind::Double => {
+                        // Needs to be in sync with kind::Single
+                        // editing.is_some()
+                        let position = if target > 0.0 {
+                            find_cursor_position(
+                                cell_bounds,
+                                &value,
+                                self,
+                                cell,
+                                target,
+                                cursor_position.y - cell_bounds.y,
+                            )
+                        } else {
+                            None
+                        }
+                        .unwrap_or(0);
+
+                        if self.keyboard_modifiers.shift() {
+                            self.cursor
+                                .select_range(self.cursor.start(&value), position);
+                        } else {
+                            self.cursor.move_to(position);
+                        }
+
+                        self.is_text_dragging = true;
+
+                        self.last_click = Some(click);
+                        self.editing = Some(Editing::Cell {
+                            index: idx,
+                            value,
+                            is_header,
+                            history: History::default(),
+                        });
+                    }
+                    click::Kind::Triple if self.editing.is_some() => {
+                        self.cursor.select_all(&value);
+                        self.is_text_dragging = false;
+
+                        self.last_click = Some(click);
+                        self.editing = Some(Editing::Cell {
+                            index: idx,
+                            value,
+                            is_header,
+                            history: History::default(),
+                        });
+                    }
+                    // todo!: Cannot realistically trigger this condition atm
+                    click::Kind::Triple => {
+                        self.last_click = Some(click);
+                        self.reset_editing();
+                        let row = row + (self.page * table.page_limit);
+                        self.selection
+                            .replace(Selection::row(row, table.cols.saturating_sub(1)));
+
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            // Guaranteed by the Selection::row above
+                            let action = Action::Selection(self.selection.clone().unwrap());
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+                    }
                 }
-            }
-        } else {
-            (None, 0.0, false)
-        };
-
-        let draw = |renderer: &mut Renderer| {
-            let paragraph = cell.raw();
 
-            let alignment_offset =
-                alignment_offset(clipped_bounds.width, paragraph.min_width(), alignment);
+                if self.editing.is_none() && self.selection_drag.is_none() && !table.read_only {
+                    self.motion = self
+                        .selection
+                        .as_ref()
+                        .and_then(|selection| selection.motion());
+                }
 
-            if let Some((cursor, color)) = cursor {
-                <Renderer as advanced::Renderer>::with_translation(
-                    renderer,
-                    Vector::new(alignment_offset - offset, 0.0),
-                    |renderer| {
-                        <Renderer as advanced::Renderer>::fill_quad(renderer, cursor, color);
-                    },
-                );
-            } else {
-                <Renderer as advanced::Renderer>::with_translation(renderer, Vector::ZERO, |_| {});
+                event::Status::Captured
             }
-        };
+            None => {
+                self.reset();
 
-        if is_selecting {
-            <Renderer as advanced::Renderer>::with_layer(renderer, clipped_bounds, |renderer| {
-                draw(renderer)
-            });
-        } else {
-            draw(renderer);
+                event::Status::Ignored
+            }
         }
     }
 
-    pub fn draw<Raw: RawTable, Message, Theme: Catalog>(
-        &self,
+    #[allow(clippy::too_many_arguments)]
+    fn update_cells<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &mut Renderer,
+        renderer: &Renderer,
+        event: event::Event,
         layout: layout::Layout<'_>,
-        style: Style,
         cursor: mouse::Cursor,
-        viewport: &Rectangle,
-    ) {
-        let padding = table.padding;
-        let spacing = table.spacing;
-
-        let bounds = layout.bounds();
-        let mut children = layout.children();
-        let cells = children.next().expect("Widget draw: Missing cells layout");
-        let status = children.next().expect("Widget draw: Missing status layout");
-        let pagination = children
-            .next()
-            .expect("Widget draw: Missing pagination layout");
-        let goto = children.next().expect("Widget draw: Missing goto layout");
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        scroll_bounds: Size,
+    ) -> event::Status {
+        if table.raw.is_empty() {
+            return event::Status::Ignored;
+        }
 
-        let cells_bounds = {
-            let width = bounds.width - padding.horizontal() + CELL_GAP;
-            let diff = padding.vertical()
-                + pagination.bounds().height.max(goto.bounds().height)
-                + if table.multiple_pages() { spacing } else { 0.0 }
-                + status.bounds().height
-                + spacing;
+        let font = table.font.unwrap_or_else(|| renderer.default_font());
+        let header_font = table.header_font.unwrap_or_else(|| renderer.default_font());
+        let size = table.text_size.unwrap_or_else(|| renderer.default_size());
+        let header_size = table.header_text_size.unwrap_or(size);
+        let padding = table.cell_padding;
 
-            let height = bounds.height - diff;
+        if matches!(
+            &event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return self.update_cells_click(table, layout, cursor, shell);
+        }
 
-            let size = Size::new(width, height);
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let mut children = layout.children();
+                let _numbering = children
+                    .next()
+                    .expect("Widget Update: Missing numbering cells");
+                let headers = children
+                    .next()
+                    .expect("Widget Update: Missing header cells")
+                    .children()
+                    .map(|child| (true, child));
+                let cells = children
+                    .next()
+                    .expect("Widget Update: Missing cells")
+                    .children()
+                    .map(|child| (false, child));
+                let children = headers.chain(cells);
 
-            let y = bounds.y + diff - padding.bottom;
-            let x = bounds.x + padding.left;
+                match children
+                    .enumerate()
+                    .find(|(_, (_, child))| cursor.is_over(child.bounds()))
+                {
+                    Some((idx, (is_header, cell))) => {
+                        let cell_bounds = cell.bounds();
+                        let cell = if is_header {
+                            cell.children()
+                                .next()
+                                .expect("Table Update: Resize node missing pair layout")
+                                .children()
+                                .next()
+                                .expect("Table Update: Pair node missing label layout")
+                        } else {
+                            cell.children()
+                                .next()
+                                .expect("Table Update: Resize node missing child layout")
+                        };
 
-            Rectangle::new(Point::new(x, y), size)
-        };
+                        let (row, column) = if is_header {
+                            (0, idx + 1)
+                        } else {
+                            let idx = idx - table.cols;
+                            let column = (idx / table.page_limit) + 1;
+                            let row = (idx + 1) - ((idx / table.page_limit) * table.page_limit);
+                            (row, column)
+                        };
 
-        if let Some(clipped_viewport) = cells_bounds.intersection(viewport) {
-            self.draw_cells(
-                table,
-                renderer,
-                cells,
-                style,
-                clipped_viewport,
-                table.cell_padding,
-            )
-        };
+                        let resize = Resizing::new(cell_bounds, cell.bounds(), cursor, row, column);
+                        self.resizing = resize;
 
-        self.draw_status(renderer, status, style, viewport);
+                        if resize.is_some() {
+                            self.reset_editing();
+                            return event::Status::Captured;
+                        }
 
-        if table.multiple_pages() {
-            self.draw_pagination(table, renderer, pagination, style, cursor, viewport);
+                        let Some(cursor_position) = cursor.position_over(cell_bounds) else {
+                            return event::Status::Ignored;
+                        };
 
-            self.draw_goto(renderer, goto, style, cursor, viewport);
-        }
+                        let (idx, cell, value) = if is_header {
+                            let (cell, _) = &self.headers[idx];
 
-        if let Some(Editing::Goto(bounds)) = &self.editing {
-            self.draw_edit(
-                renderer,
-                style,
-                &self.goto_input.0,
-                *bounds,
-                *bounds,
-                &self.goto_input.1,
-                self.goto_input.0.horizontal_alignment(),
-            )
-        };
-    }
+                            let value = table.raw.column_header(idx).unwrap_or_default();
 
-    fn interaction_cells(
-        &self,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-    ) -> mouse::Interaction {
-        let mut children = layout.children();
-        let _numbering = children
-            .next()
-            .expect("Widget Interaction: Missing numbering cells");
-        let headers = children
-            .next()
-            .expect("Widget Interaction: Missing header cells");
+                            (idx, cell, value)
+                        } else {
+                            let idx = idx - table.cols;
+                            let cell = &self.cells[idx];
+                            let (row, column) = (idx % table.page_limit, idx / table.page_limit);
+                            let row = row + (self.page * table.page_limit);
 
-        for (idx, resize) in headers.children().enumerate() {
-            let pair = resize
-                .children()
-                .next()
-                .expect("Table Interaction: Resize node missing pair layout");
+                            let value = table
+                                .raw
+                                .cell(row, column)
+                                .map(Cow::into_owned)
+                                .unwrap_or_default();
 
-            let resize = resize.bounds();
+                            (idx, cell, value)
+                        };
 
-            let label = pair
-                .children()
-                .next()
-                .expect("Table Interaction: Pair node missing label layout")
-                .bounds();
+                        let target = {
+                            let alignment_offset = alignment_offset(
+                                cell_bounds.width,
+                                cell.min_width(),
+                                cell.horizontal_alignment(),
+                            );
 
-            let pair = pair.bounds();
+                            cursor_position.x - cell_bounds.x - alignment_offset
+                        };
 
-            match &self.editing {
-                Some(Editing::Cell {
-                    index,
-                    is_header: true,
-                    ..
-                }) if *index == idx && cursor.is_over(label) => {
-                    return mouse::Interaction::Text;
-                }
-                _ if cursor.is_over(pair) => {
-                    return mouse::Interaction::Cell;
-                }
-                _ if cursor.is_over(resize) => {
-                    let horizontal = {
-                        let position = resize.position() + Vector::new(pair.width, 0.0);
-                        let height = resize.height;
-                        let width = resize.width - pair.width;
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            mouse::Button::Left,
+                            self.last_click,
+                        );
 
-                        let horizontal = Rectangle::new(position, Size::new(width, height));
+                        match click.kind() {
+                            click::Kind::Single => {
+                                let position = if target > 0.0 {
+                                    find_cursor_position(
+                                        cell_bounds,
+                                        &value,
+                                        self,
+                                        cell,
+                                        target,
+                                        cursor_position.y - cell_bounds.y,
+                                    )
+                                } else {
+                                    None
+                                }
+                                .unwrap_or(0);
 
-                        cursor.is_over(horizontal)
-                    };
-                    let vertical = {
-                        let position = resize.position() + Vector::new(0.0, pair.height);
-                        let width = resize.width;
-                        let height = resize.height - pair.height;
+                                if self.keyboard_modifiers.shift() {
+                                    self.cursor
+                                        .select_range(self.cursor.start(&value), position);
+                                } else {
+                                    self.cursor.move_to(position);
+                                }
 
-                        let vertical = Rectangle::new(position, Size::new(width, height));
-                        cursor.is_over(vertical)
-                    };
+                                self.is_text_dragging = true;
+                            }
+                            click::Kind::Double => {
+                                let position = find_cursor_position(
+                                    cell_bounds,
+                                    &value,
+                                    self,
+                                    cell,
+                                    target,
+                                    cursor_position.y - cell_bounds.y,
+                                )
+                                .unwrap_or(0);
+                                let (start, end) = word_boundary(&value, position);
+                                self.cursor.select_range(start, end);
+                                self.is_text_dragging = false;
+                            }
+                            click::Kind::Triple => {
+                                self.cursor.select_all(&value);
+                                self.is_text_dragging = false;
+                            }
+                        }
 
-                    if vertical && horizontal {
-                        return mouse::Interaction::ResizingDiagonallyDown;
-                    }
+                        self.last_click = Some(click);
+                        self.editing = Some(Editing::Cell {
+                            index: idx,
+                            value,
+                            is_header,
+                            history: History::default(),
+                        });
 
-                    if vertical {
-                        return mouse::Interaction::ResizingVertically;
+                        event::Status::Captured
                     }
+                    None => {
+                        self.reset();
 
-                    if horizontal {
-                        return mouse::Interaction::ResizingHorizontally;
+                        event::Status::Ignored
                     }
                 }
-                _ => {}
             }
-        }
-
-        let cells = children.next().expect("Widget Interaction: Missing cells");
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.is_text_dragging =>
+            {
+                self.reset_resizing();
 
-        for (idx, cell) in cells.children().enumerate() {
-            let resize = cell.bounds();
-            let child = cell
-                .children()
-                .next()
-                .expect("Table Interaction: Resize node missing child layout")
-                .bounds();
+                if self.auto_scroll_cells(layout, scroll_bounds, position) {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
 
-            match &self.editing {
-                Some(Editing::Cell {
+                let Some(Editing::Cell {
                     index,
-                    is_header: false,
+                    value,
+                    is_header,
                     ..
-                }) if *index == idx && cursor.is_over(child) => {
-                    return mouse::Interaction::Text;
-                }
-                _ if cursor.is_over(child) => {
-                    return mouse::Interaction::Cell;
-                }
-                _ if cursor.is_over(resize) => {
-                    let horizontal = {
-                        let position = resize.position() + Vector::new(child.width, 0.0);
-                        let height = resize.height;
-                        let width = resize.width - child.width;
+                }) = &self.editing
+                else {
+                    return event::Status::Ignored;
+                };
 
-                        let horizontal = Rectangle::new(position, Size::new(width, height));
+                let mut children = layout.children();
+                let _numbering = children.next();
+                let headers = children
+                    .next()
+                    .expect("Widget Update: Missing header cells")
+                    .children();
+                let cells = children
+                    .next()
+                    .expect("Widget Update: Missing cells")
+                    .children();
 
-                        cursor.is_over(horizontal)
-                    };
-                    let vertical = {
-                        let position = resize.position() + Vector::new(0.0, child.height);
-                        let width = resize.width;
-                        let height = resize.height - child.height;
+                let (bounds, cell) = if *is_header {
+                    let bounds = headers
+                        .enumerate()
+                        .find(|(idx, _)| *idx == *index)
+                        // Pair node
+                        .and_then(|(_, resize)| resize.children().next())
+                        // Label node
+                        .and_then(|pair| pair.children().next())
+                        .map(|label| label.bounds())
+                        .expect("Table Update: Editing selection header missing layout");
+                    let (cell, _) = &self.headers[*index];
+                    (bounds, cell)
+                } else {
+                    let bounds = cells
+                        .enumerate()
+                        .find(|(idx, _)| *idx == *index)
+                        .map(|(_, resize)| {
+                            resize
+                                .children()
+                                .next()
+                                .expect("Table Update: Editing resize node missing cell layout")
+                                .bounds()
+                        })
+                        .expect("Table Update: Editing selection missing layout");
+                    let cell = &self.cells[*index];
+                    (bounds, cell)
+                };
 
-                        let vertical = Rectangle::new(position, Size::new(width, height));
-                        cursor.is_over(vertical)
-                    };
+                let target = {
+                    let alignment_offset = alignment_offset(
+                        bounds.width,
+                        cell.min_width(),
+                        cell.horizontal_alignment(),
+                    );
 
-                    if vertical && horizontal {
-                        return mouse::Interaction::ResizingDiagonallyDown;
-                    }
+                    position.x - bounds.x - alignment_offset
+                };
 
-                    if vertical {
-                        return mouse::Interaction::ResizingVertically;
-                    }
+                let position =
+                    find_cursor_position(bounds, value, self, cell, target, position.y - bounds.y)
+                        .unwrap_or(0);
 
-                    if horizontal {
-                        return mouse::Interaction::ResizingHorizontally;
-                    }
-                }
-                _ => {}
+                self.cursor.select_range(self.cursor.start(value), position);
+
+                event::Status::Captured
             }
-        }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.selection_drag.is_some() =>
+            {
+                let Some((anchor_row, anchor_column)) = self.selection_drag else {
+                    return event::Status::Ignored;
+                };
 
-        mouse::Interaction::None
-    }
+                if self.auto_scroll_cells(layout, scroll_bounds, position) {
+                    shell.invalidate_layout();
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
 
-    fn interaction_pagination<Raw: RawTable, Message, Theme: Catalog>(
-        &self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-    ) -> mouse::Interaction {
-        let mut children = layout.children();
+                let Some(index) = layout
+                    .children()
+                    .nth(2)
+                    .expect("Widget Update: Missing Cells layout")
+                    .children()
+                    .position(|child| child.bounds().contains(position))
+                else {
+                    return event::Status::Captured;
+                };
 
-        let back = children
-            .next()
-            .expect("Widget Interaction: missing paginations: Back");
+                let (row, column) = (index % table.page_limit, index / table.page_limit);
+                let row = row + (self.page * table.page_limit);
 
-        if cursor.is_over(back.bounds()) && self.page != 0 {
-            return mouse::Interaction::Pointer;
-        }
+                let selection = Selection::column_range(
+                    anchor_column.min(column)..=anchor_column.max(column),
+                    anchor_row.min(row)..=anchor_row.max(row),
+                );
 
-        let pages = children
-            .next()
-            .expect("Widget Interaction: missing paginations: Pages");
+                if self.selection.as_ref() != Some(&selection) {
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let action = Action::Selection(selection.clone());
+                        let msg = on_action(action);
+                        shell.publish(msg);
+                    }
 
-        if pages.children().any(|page| cursor.is_over(page.bounds())) {
-            return mouse::Interaction::Pointer;
-        }
+                    self.selection = Some(selection);
+                }
 
-        let next = children
-            .next()
-            .expect("Widget Interaction: missing paginations: Next");
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.motion.is_some() =>
+            {
+                let Some(motion) = self.motion.as_mut() else {
+                    return event::Status::Ignored;
+                };
 
-        if cursor.is_over(next.bounds()) && self.page != table.pages_end() {
-            return mouse::Interaction::Pointer;
-        }
+                let mut children = layout.children();
+                let numbering = children
+                    .next()
+                    .expect("Widget Update: Missing numbering cells")
+                    .children()
+                    .enumerate()
+                    .find_map(|(index, child)| {
+                        if child.bounds().contains(position) {
+                            Some(index)
+                        } else {
+                            None
+                        }
+                    });
+                let headers = children
+                    .next()
+                    .expect("Widget Update: Missing header cells")
+                    .children()
+                    .enumerate()
+                    .find_map(|(index, child)| {
+                        if child.bounds().contains(position) {
+                            Some(index)
+                        } else {
+                            None
+                        }
+                    });
 
-        mouse::Interaction::None
-    }
+                let cells = children
+                    .next()
+                    .expect("Widget Update: Missing Cells layout")
+                    .children()
+                    .enumerate()
+                    .find_map(|(index, child)| {
+                        if child.bounds().contains(position) {
+                            Some(index)
+                        } else {
+                            None
+                        }
+                    });
 
-    fn interaction_goto(
-        &self,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-    ) -> mouse::Interaction {
-        let mut children = layout.children();
-        let _ = children.next();
+                match motion {
+                    Motion::Cell {
+                        d_column, d_row, ..
+                    } => {
+                        if let Some(numbering) = numbering {
+                            *d_row = numbering.wrapping_sub(1);
+                            return event::Status::Captured;
+                        }
+
+                        if let Some(header) = headers {
+                            *d_column = header;
+                            return event::Status::Captured;
+                        }
+
+                        if let Some(index) = cells {
+                            let (row, column) =
+                                (index % table.page_limit, index / table.page_limit);
+                            let row = row + (self.page * table.page_limit);
+                            *d_row = row;
+                            *d_column = column;
+                            return event::Status::Captured;
+                        }
+                    }
+                    Motion::Column { dst, .. } => {
+                        if let Some(index) = headers {
+                            *dst = index;
+                            return event::Status::Captured;
+                        }
 
-        let input = children
-            .next()
-            .expect("Widget interaction: Missing goto input layout");
+                        if let Some(cell) = cells {
+                            let column = cell / table.page_limit;
+                            *dst = column;
+                            return event::Status::Captured;
+                        }
+                    }
+                    Motion::Row { dst, .. } => {
+                        if let Some(index) = numbering {
+                            *dst = index.saturating_sub(1) + (self.page * table.page_limit);
+                            return event::Status::Captured;
+                        }
 
-        if cursor.is_over(input.bounds()) {
-            return mouse::Interaction::Text;
-        }
+                        if let Some(cell) = cells {
+                            let row = cell % table.page_limit;
+                            let row = row + (self.page * table.page_limit);
+                            *dst = row;
+                            return event::Status::Captured;
+                        }
+                    }
+                }
 
-        let go = children
-            .next()
-            .expect("Widget Interaction: Missing goto go layout");
-        if cursor.is_over(go.bounds()) {
-            return mouse::Interaction::Pointer;
-        }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.resizing.is_some() =>
+            {
+                let Some(mut resize) = self.resizing else {
+                    return event::Status::Ignored;
+                };
+                let diff = self.drag_resize(table, &mut resize, position, shell);
+                self.resizing = Some(resize);
 
-        mouse::Interaction::None
-    }
+                self.scroll_cells(scroll_bounds, diff);
 
-    pub fn mouse_interaction<Raw: RawTable, Message, Theme: Catalog>(
-        &self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-    ) -> mouse::Interaction {
-        if let Some(interaction) = self.resizing.map(|resize| resize.interaction()) {
-            return interaction;
-        }
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) => {
+                let Some(focus) = self.is_focused.as_mut() else {
+                    return event::Status::Ignored;
+                };
 
-        if self.motion.is_some() {
-            return mouse::Interaction::Grabbing;
-        }
+                let Some(Editing::Cell {
+                    index,
+                    value,
+                    is_header,
+                    history,
+                }) = self.editing.as_mut()
+                else {
+                    return event::Status::Ignored;
+                };
 
-        let mut children = layout.children();
+                let index = *index;
+                let modifiers = self.keyboard_modifiers;
+                focus.updated_at = Instant::now();
 
-        let cells = children
-            .next()
-            .expect("Widget Interaction: Missing cells layout");
-        if cursor.is_over(cells.bounds()) {
-            return self.interaction_cells(cells, cursor);
-        }
+                let (cell, col_kind, row, column) = if *is_header {
+                    let (cell, _) = &mut self.headers[index];
+                    let col = table
+                        .raw
+                        .column_kind(index)
+                        .expect("Cells update: Missing column in sheet");
 
-        let _status = children.next();
+                    (cell, col, 0, index + 1)
+                } else {
+                    self.shaped.remove(&index);
+                    let cell = &mut self.cells[index];
+                    let (row, column) = (index % table.page_limit, index / table.page_limit);
+                    let row = row + (self.page * table.page_limit);
 
-        if table.multiple_pages() {
-            let pagination = children
-                .next()
-                .expect("Widget Interaction: Missing pagination layout");
-            if cursor.is_over(pagination.bounds()) {
-                return self.interaction_pagination(table, pagination, cursor);
-            }
+                    let col = table
+                        .raw
+                        .column_kind(column)
+                        .expect("Cells update: Missing column in sheet");
 
-            let goto = children
-                .next()
-                .expect("Widget Interaction: Missing goto layout");
-            if cursor.is_over(goto.bounds()) {
-                return self.interaction_goto(goto, cursor);
-            }
-        }
+                    (cell, col, row, column)
+                };
 
-        mouse::Interaction::None
-    }
+                if key.as_ref() == keyboard::Key::Character("a") && modifiers.command() {
+                    self.cursor.select_all(value);
+                    return event::Status::Captured;
+                }
 
-    fn update_cells_click<Raw: RawTable, Message, Theme: Catalog>(
-        &mut self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-        shell: &mut Shell<'_, Message>,
-    ) -> event::Status {
-        let padding = table.cell_padding;
-        let mut children = layout.children();
-        let numbering = children
-            .next()
-            .expect("Widget Update: Missing numbering cells");
+                if key.as_ref() == keyboard::Key::Character("c") && modifiers.command() {
+                    if let Some(selected) = self.cursor.selected(value) {
+                        clipboard.write(advanced::clipboard::Kind::Standard, selected.to_owned());
+                    }
 
-        if let Some((idx, numbering)) = numbering
-            .children()
-            .enumerate()
-            .filter(|(idx, _)| *idx != 0)
-            .find(|(_, child)| cursor.is_over(child.bounds()))
-        {
-            let row = idx - 1;
-            let row = row + (self.page * table.page_limit);
-            let bounds = numbering.bounds();
-            // Guaranteed by the find above
-            let cursor_position = cursor.position_over(bounds).unwrap();
-            let click = mouse::Click::new(cursor_position, mouse::Button::Left, self.last_click);
+                    return event::Status::Captured;
+                }
 
-            self.last_click = Some(click);
-            self.reset_editing();
-            self.selection
-                .replace(Selection::row(row, table.cols.saturating_sub(1)));
+                let is_undo = matches!(
+                    key.as_ref(),
+                    keyboard::Key::Character("z") | keyboard::Key::Character("Z")
+                ) && modifiers.command();
+                let is_redo = (is_undo && modifiers.shift())
+                    || (key.as_ref() == keyboard::Key::Character("y") && modifiers.command());
+                let is_cut = key.as_ref() == keyboard::Key::Character("x") && modifiers.command();
+                let is_paste = key.as_ref() == keyboard::Key::Character("v") && modifiers.command();
+
+                if is_undo || is_redo || is_cut || is_paste {
+                    let mut editor = Editor::with_history(value, &mut self.cursor, history);
+                    let is_changed = if is_redo {
+                        editor.redo()
+                    } else if is_undo {
+                        editor.undo()
+                    } else if is_cut {
+                        editor
+                            .cut()
+                            .map(|selected| {
+                                clipboard.write(advanced::clipboard::Kind::Standard, selected)
+                            })
+                            .is_some()
+                    } else {
+                        let contents = clipboard
+                            .read(advanced::clipboard::Kind::Standard)
+                            .unwrap_or_default();
+
+                        editor.insert_str(
+                            &contents,
+                            |c| {
+                                !c.is_control()
+                                    && (*is_header || table.accepts(&col_kind, column, c))
+                            },
+                            |value| *is_header || table.validates(&col_kind, column, value),
+                        )
+                    };
 
-            if let Some(on_action) = table.on_action.as_ref() {
-                // Guaranteed by the Selection::row above
-                let action = Action::Selection(self.selection.clone().unwrap());
-                let msg = on_action(action);
-                shell.publish(msg);
-            }
-            if self.editing.is_none() {
-                self.motion = self
-                    .selection
-                    .as_ref()
-                    .and_then(|selection| selection.motion());
-            }
-            return event::Status::Captured;
-        }
+                    if !is_changed {
+                        return event::Status::Captured;
+                    }
 
-        let headers = children
-            .next()
-            .expect("Widget Update: Missing header cells")
-            .children()
-            .map(|child| (true, child));
-        let cells = children
-            .next()
-            .expect("Widget Update: Missing cells")
-            .children()
-            .map(|child| (false, child));
-        let children = headers.chain(cells);
+                    let bounds = if *is_header {
+                        self.max_cell
+                    } else {
+                        table
+                            .wrap_bounds(self.min_widths[column + 1], size)
+                            .unwrap_or(self.max_cell)
+                    };
 
-        match children
-            .enumerate()
-            .find(|(_, (_, child))| cursor.is_over(child.bounds()))
-        {
-            Some((idx, (is_header, cell))) => {
-                let cell_bounds = cell.bounds();
-                let cell = cell
-                    .children()
-                    .next()
-                    .expect("Table Update: Resize node missing child layout");
+                    cell.update(super::text::<Renderer>(
+                        value,
+                        bounds,
+                        if *is_header { header_font } else { font },
+                        cell.horizontal_alignment(),
+                        if *is_header { header_size } else { size },
+                        self.shaping,
+                        self.line_height,
+                    ));
 
-                let cursor_position = cursor.position_over(cell.bounds());
+                    if *is_header {
+                        if let Some(on_action) = table.on_action.as_ref() {
+                            let action =
+                                Action::header_input(value.clone(), column.saturating_sub(1));
+                            let msg = on_action(action);
+                            shell.publish(msg);
+                        }
+                    } else if let Some(on_action) = table.on_action.as_ref() {
+                        let action = Action::cell_input(value.clone(), column, row);
+                        let msg = on_action(action);
+                        shell.publish(msg)
+                    }
 
-                let (row, column) = if is_header {
-                    (0, idx + 1)
-                } else {
-                    let idx = idx - table.cols;
-                    let column = (idx / table.page_limit) + 1;
-                    let row = (idx + 1) - ((idx / table.page_limit) * table.page_limit);
-                    (row, column)
-                };
+                    let (row, column) = if *is_header {
+                        (row, column)
+                    } else {
+                        ((index % table.page_limit) + 1, column + 1)
+                    };
+                    let min_bounds = cell.min_bounds().expand(padding);
 
-                let resize = Resizing::new(cell_bounds, cell.bounds(), cursor, row, column);
+                    if self.grow_to_fit(row, column, min_bounds, table.max_column_width) {
+                        shell.invalidate_layout();
+                    }
 
-                if resize.is_some() {
-                    self.resizing = resize;
-                    self.reset_editing();
                     return event::Status::Captured;
                 }
 
-                let Some(cursor_position) = cursor_position else {
-                    return event::Status::Ignored;
-                };
-
-                let click =
-                    mouse::Click::new(cursor_position, mouse::Button::Left, self.last_click);
-
-                let (row, column) = if is_header {
-                    (0, idx)
-                } else {
-                    let idx = idx - table.cols;
-                    let (row, column) = (idx % table.page_limit, idx / table.page_limit);
-                    let row = row + (self.page * table.page_limit);
-                    (row, column)
-                };
+                match text {
+                    Some(text) if *is_header => {
+                        if let Some(c) = text.chars().next().filter(|c| !c.is_control()) {
+                            let mut editor = Editor::with_history(value, &mut self.cursor, history);
+                            editor.insert(c);
 
-                let cell_bounds = cell.bounds().shrink(padding);
+                            cell.update(super::text::<Renderer>(
+                                value,
+                                self.max_cell,
+                                header_font,
+                                cell.horizontal_alignment(),
+                                header_size,
+                                self.shaping,
+                                self.line_height,
+                            ));
 
-                let Some(cursor_position) = cursor.position_over(cell_bounds) else {
-                    return event::Status::Ignored;
-                };
+                            focus.updated_at = Instant::now();
 
-                let (idx, cell, value) = if is_header {
-                    let (cell, _) = &self.headers[idx];
+                            if let Some(on_action) = table.on_action.as_ref() {
+                                let action =
+                                    Action::header_input(value.clone(), column.saturating_sub(1));
+                                let msg = on_action(action);
+                                shell.publish(msg);
+                            }
 
-                    let value = table.raw.column_header(idx).unwrap_or_default();
+                            let min_bounds = cell.min_bounds().expand(padding);
 
-                    (idx, cell, value)
-                } else {
-                    let idx = idx - table.cols;
-                    let cell = &self.cells[idx];
-                    let (row, column) = (idx % table.page_limit, idx / table.page_limit);
-                    let row = row + (self.page * table.page_limit);
+                            if self.grow_to_fit(row, column, min_bounds, table.max_column_width) {
+                                shell.invalidate_layout();
+                            }
 
-                    let value = table.raw.cell(row, column).unwrap_or_default();
+                            return event::Status::Captured;
+                        }
+                    }
+                    Some(text) => {
+                        if let Some(c) = text.chars().next().filter(|c| {
+                            let column = if *is_header { index } else { column };
+                            !c.is_control()
+                                && table.accepts(&col_kind, column, *c)
+                                && table.validates(
+                                    &col_kind,
+                                    column,
+                                    &self.cursor.replaced(value, &c.to_string()),
+                                )
+                        }) {
+                            let mut editor = Editor::with_history(value, &mut self.cursor, history);
+                            editor.insert(c);
 
-                    (idx, cell, value)
-                };
+                            let bounds = table
+                                .wrap_bounds(self.min_widths[column + 1], size)
+                                .unwrap_or(self.max_cell);
 
-                let target = {
-                    let alignment_offset = alignment_offset(
-                        cell_bounds.width,
-                        cell.min_width(),
-                        cell.horizontal_alignment(),
-                    );
+                            cell.update(super::text::<Renderer>(
+                                value,
+                                bounds,
+                                font,
+                                cell.horizontal_alignment(),
+                                size,
+                                self.shaping,
+                                self.line_height,
+                            ));
 
-                    cursor_position.x - cell_bounds.x - alignment_offset
-                };
+                            focus.updated_at = Instant::now();
 
-                let (editing_idx, editing_is_header) = match self.editing.as_ref() {
-                    Some(Editing::Cell {
-                        index, is_header, ..
-                    }) => (Some(*index), *is_header),
-                    _ => (None, false),
-                };
+                            if let Some(on_action) = table.on_action.as_ref() {
+                                let action = Action::cell_input(value.clone(), column, row);
+                                let msg = on_action(action);
+                                shell.publish(msg);
+                            }
 
-                match click.kind() {
-                    click::Kind::Single if self.keyboard_modifiers.shift() && !is_header => {
-                        self.last_click = Some(click);
-                        let Some(selection) = self.selection.as_mut() else {
-                            return event::Status::Ignored;
-                        };
+                            let column = column + 1;
+                            let row = (index % table.page_limit) + 1;
+                            let min_bounds = cell.min_bounds().expand(padding);
 
-                        selection.block(row, column);
+                            if self.grow_to_fit(row, column, min_bounds, table.max_column_width) {
+                                shell.invalidate_layout();
+                            }
+                            return event::Status::Captured;
+                        }
+                    }
+                    None => {}
+                }
 
+                match key.as_ref() {
+                    keyboard::Key::Named(keyboard::key::Named::Enter) if *is_header => {
                         if let Some(on_action) = table.on_action.as_ref() {
-                            let action = Action::Selection(selection.clone());
+                            let action =
+                                Action::header_submit(value.clone(), column.saturating_sub(1));
                             let msg = on_action(action);
-                            shell.publish(msg);
+                            shell.publish(msg)
                         }
+                        self.edited = None;
 
-                        self.reset_editing();
+                        self.reset();
+                        shell.invalidate_layout();
+                        event::Status::Captured
                     }
-                    click::Kind::Single if self.keyboard_modifiers.command() && !is_header => {
-                        self.last_click = Some(click);
-                        let Some(selection) = self.selection.as_mut() else {
-                            return event::Status::Ignored;
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        let value = value.clone();
+                        self.submit_vertical(table, value, row, column, !modifiers.shift(), shell);
+                        event::Status::Captured
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        let mut editor = Editor::with_history(value, &mut self.cursor, history);
+
+                        if modifiers.command() {
+                            editor.backspace_word();
+                        } else {
+                            editor.backspace();
+                        }
+
+                        let bounds = if *is_header {
+                            self.max_cell
+                        } else {
+                            table
+                                .wrap_bounds(self.min_widths[column + 1], size)
+                                .unwrap_or(self.max_cell)
                         };
-                        selection.scattered(row, column);
 
-                        if let Some(on_action) = table.on_action.as_ref() {
-                            let action = Action::Selection(selection.clone());
+                        cell.update(super::text::<Renderer>(
+                            value,
+                            bounds,
+                            if *is_header { header_font } else { font },
+                            cell.horizontal_alignment(),
+                            if *is_header { header_size } else { size },
+                            self.shaping,
+                            self.line_height,
+                        ));
+
+                        if *is_header {
+                            if let Some(on_action) = table.on_action.as_ref() {
+                                let action =
+                                    Action::header_input(value.clone(), column.saturating_sub(1));
+                                let msg = on_action(action);
+                                shell.publish(msg);
+                            }
+                        } else if let Some(on_action) = table.on_action.as_ref() {
+                            let action = Action::cell_input(value.clone(), column, row);
                             let msg = on_action(action);
-                            shell.publish(msg);
+                            shell.publish(msg)
                         }
 
-                        self.reset_editing();
+                        event::Status::Captured
                     }
-                    click::Kind::Single
-                        if editing_idx.is_some()
-                            && editing_idx.unwrap() == idx
-                            && is_header == editing_is_header =>
-                    {
-                        // Needs to be in sync with kind::Double
-                        let position = if target > 0.0 {
-                            find_cursor_position(cell_bounds, &value, self, cell, target)
-                        } else {
-                            None
-                        }
-                        .unwrap_or(0);
+                    keyboard::Key::Named(keyboard::key::Named::Delete) => {
+                        let mut editor = Editor::with_history(value, &mut self.cursor, history);
 
-                        if self.keyboard_modifiers.shift() {
-                            self.cursor
-                                .select_range(self.cursor.start(&value), position);
+                        if modifiers.command() {
+                            editor.delete_word();
                         } else {
-                            self.cursor.move_to(position);
+                            editor.delete();
                         }
 
-                        self.is_text_dragging = true;
+                        let bounds = if *is_header {
+                            self.max_cell
+                        } else {
+                            table
+                                .wrap_bounds(self.min_widths[column + 1], size)
+                                .unwrap_or(self.max_cell)
+                        };
 
-                        self.last_click = Some(click);
-                        self.editing = Some(Editing::Cell {
-                            index: idx,
+                        cell.update(super::text::<Renderer>(
                             value,
-                            is_header,
-                        });
-                    }
-                    click::Kind::Single if is_header => {
-                        self.last_click = Some(click);
-                        self.reset_editing();
-                        self.selection.replace(Selection::column(
-                            column,
-                            (table.page_limit * (self.page + 1)).saturating_sub(1),
+                            bounds,
+                            if *is_header { header_font } else { font },
+                            cell.horizontal_alignment(),
+                            if *is_header { header_size } else { size },
+                            self.shaping,
+                            self.line_height,
                         ));
 
-                        if let Some(on_action) = table.on_action.as_ref() {
-                            // Guaranteed by the Selection::column above
-                            let action = Action::Selection(self.selection.clone().unwrap());
+                        if *is_header {
+                            if let Some(on_action) = table.on_action.as_ref() {
+                                let action =
+                                    Action::header_input(value.clone(), column.saturating_sub(1));
+                                let msg = on_action(action);
+                                shell.publish(msg);
+                            }
+                        } else if let Some(on_action) = table.on_action.as_ref() {
+                            let action = Action::cell_input(value.clone(), column, row);
                             let msg = on_action(action);
-                            shell.publish(msg);
+                            shell.publish(msg)
                         }
+
+                        event::Status::Captured
                     }
-                    click::Kind::Single => {
-                        self.last_click = Some(click);
-                        match editing_idx {
-                            Some(index) if is_header == editing_is_header && index == idx => {}
-                            _ => self.reset_editing(),
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+                        match (modifiers.shift(), modifiers.command()) {
+                            (true, true) => self.cursor.select_word_left(value),
+                            (true, false) => self.cursor.select_left(value),
+                            (false, true) => self.cursor.move_word_left(value),
+                            (false, false) => self.cursor.move_left(value),
                         }
-                        self.selection.replace(Selection::new(row, column));
 
-                        if let Some(on_action) = table.on_action.as_ref() {
-                            // Guaranteed by the Selection::new above
-                            let action = Action::Selection(self.selection.clone().unwrap());
-                            let msg = on_action(action);
-                            shell.publish(msg);
-                        }
+                        event::Status::Captured
                     }
-                    click::Kind::Double if self.editing.is_some() => {
-                        let position =
-                            find_cursor_position(cell_bounds, &value, self, cell, target)
-                                .unwrap_or(0);
-                        let (start, end) = word_boundary(&value, position);
-                        self.cursor.select_range(start, end);
-                        self.is_text_dragging = false;
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                        match (modifiers.shift(), modifiers.command()) {
+                            (true, true) => self.cursor.select_word_right(value),
+                            (true, false) => self.cursor.select_right(value),
+                            (false, true) => self.cursor.move_word_right(value),
+                            (false, false) => self.cursor.move_right(value),
+                        }
 
-                        self.last_click = Some(click);
-                        self.editing = Some(Editing::Cell {
-                            index: idx,
-                            value,
-                            is_header,
-                        });
+                        event::Status::Captured
                     }
-                    click::Kind::Double => {
-                        // Needs to be in sync with kind::Single
-                        // editing.is_some()
-                        let position = if target > 0.0 {
-                            find_cursor_position(cell_bounds, &value, self, cell, target)
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        self.reset();
+                        event::Status::Captured
+                    }
+                    keyboard::Key::Named(
+                        named @ (keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown),
+                    ) if table.arrow_keys_commit && !*is_header && !modifiers.command() => {
+                        let value = value.clone();
+                        let down = named == keyboard::key::Named::ArrowDown;
+                        self.submit_vertical(table, value, row, column, down, shell);
+                        event::Status::Captured
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        if modifiers.shift() {
+                            self.cursor.select_to_start(value);
                         } else {
-                            None
+                            self.cursor.move_to(0);
                         }
-                        .unwrap_or(0);
 
-                        if self.keyboard_modifiers.shift() {
-                            self.cursor
-                                .select_range(self.cursor.start(&value), position);
+                        event::Status::Captured
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                        if modifiers.shift() {
+                            self.cursor.select_to_end(value);
                         } else {
-                            self.cursor.move_to(position);
+                            self.cursor.move_to_end(value);
                         }
 
-                        self.is_text_dragging = true;
-
-                        self.last_click = Some(click);
-                        self.editing = Some(Editing::Cell {
-                            index: idx,
-                            value,
-                            is_header,
-                        });
+                        event::Status::Captured
                     }
-                    click::Kind::Triple if self.editing.is_some() => {
-                        self.cursor.select_all(&value);
-                        self.is_text_dragging = false;
-
-                        self.last_click = Some(click);
-                        self.editing = Some(Editing::Cell {
-                            index: idx,
+                    keyboard::Key::Named(keyboard::key::Named::Tab) if !*is_header => {
+                        let value = value.clone();
+                        self.submit_horizontal(
+                            table,
                             value,
-                            is_header,
-                        });
-                    }
-                    // todo!: Cannot realistically trigger this condition atm
-                    click::Kind::Triple => {
-                        self.last_click = Some(click);
-                        self.reset_editing();
-                        let row = row + (self.page * table.page_limit);
-                        self.selection
-                            .replace(Selection::row(row, table.cols.saturating_sub(1)));
-
-                        if let Some(on_action) = table.on_action.as_ref() {
-                            // Guaranteed by the Selection::row above
-                            let action = Action::Selection(self.selection.clone().unwrap());
-                            let msg = on_action(action);
-                            shell.publish(msg);
-                        }
+                            row,
+                            column,
+                            !modifiers.shift(),
+                            shell,
+                        );
+                        event::Status::Captured
                     }
-                }
+                    keyboard::Key::Named(keyboard::key::Named::Tab) => event::Status::Ignored,
 
-                if self.editing.is_none() {
-                    self.motion = self
-                        .selection
-                        .as_ref()
-                        .and_then(|selection| selection.motion());
+                    _ => event::Status::Captured,
                 }
-
-                event::Status::Captured
             }
-            None => {
-                self.reset();
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn update_page_sizes<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(size) = layout
+            .children()
+            .nth(1)
+            .expect("Widget Update: Missing page sizes presets")
+            .children()
+            .position(|size| cursor.is_over(size.bounds()))
+            .and_then(|idx| table.page_sizes.get(idx))
+        else {
+            return event::Status::Ignored;
+        };
 
-                event::Status::Ignored
+        if *size != table.page_limit {
+            if let Some(on_action) = table.on_action.as_ref() {
+                let msg = on_action(Action::PageLimit(*size));
+                shell.publish(msg);
             }
         }
+
+        event::Status::Captured
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn update_cells<Raw: RawTable, Message, Theme: Catalog>(
+    fn update_pagination<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &Renderer,
         event: event::Event,
         layout: layout::Layout<'_>,
         cursor: mouse::Cursor,
         shell: &mut Shell<'_, Message>,
-        scroll_bounds: Size,
     ) -> event::Status {
-        if table.raw.is_empty() {
-            return event::Status::Ignored;
-        }
-
-        let font = table.font.unwrap_or_else(|| renderer.default_font());
-        let header_font = table.header_font.unwrap_or_else(|| renderer.default_font());
-        let size = table.text_size.unwrap_or_else(|| renderer.default_size());
-        let padding = table.cell_padding;
-
-        if matches!(
-            &event,
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. })
-        ) {
-            return self.update_cells_click(table, layout, cursor, shell);
-        }
-
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mut children = layout.children();
-                let _numbering = children
-                    .next()
-                    .expect("Widget Update: Missing numbering cells");
-                let headers = children
-                    .next()
-                    .expect("Widget Update: Missing header cells")
-                    .children()
-                    .map(|child| (true, child));
-                let cells = children
-                    .next()
-                    .expect("Widget Update: Missing cells")
-                    .children()
-                    .map(|child| (false, child));
-                let children = headers.chain(cells);
-
-                match children
-                    .enumerate()
-                    .find(|(_, (_, child))| cursor.is_over(child.bounds()))
-                {
-                    Some((idx, (is_header, cell))) => {
-                        let cell_bounds = cell.bounds();
-                        let cell = if is_header {
-                            cell.children()
-                                .next()
-                                .expect("Table Update: Resize node missing pair layout")
-                                .children()
-                                .next()
-                                .expect("Table Update: Pair node missing label layout")
-                        } else {
-                            cell.children()
-                                .next()
-                                .expect("Table Update: Resize node missing child layout")
-                        };
-
-                        let (row, column) = if is_header {
-                            (0, idx + 1)
-                        } else {
-                            let idx = idx - table.cols;
-                            let column = (idx / table.page_limit) + 1;
-                            let row = (idx + 1) - ((idx / table.page_limit) * table.page_limit);
-                            (row, column)
-                        };
-
-                        let resize = Resizing::new(cell_bounds, cell.bounds(), cursor, row, column);
-                        self.resizing = resize;
-
-                        if resize.is_some() {
-                            self.reset_editing();
-                            return event::Status::Captured;
-                        }
-
-                        let Some(cursor_position) = cursor.position_over(cell_bounds) else {
-                            return event::Status::Ignored;
-                        };
-
-                        let (idx, cell, value) = if is_header {
-                            let (cell, _) = &self.headers[idx];
-
-                            let value = table.raw.column_header(idx).unwrap_or_default();
-
-                            (idx, cell, value)
-                        } else {
-                            let idx = idx - table.cols;
-                            let cell = &self.cells[idx];
-                            let (row, column) = (idx % table.page_limit, idx / table.page_limit);
-                            let row = row + (self.page * table.page_limit);
 
-                            let value = table.raw.cell(row, column).unwrap_or_default();
+                let back = children
+                    .next()
+                    .expect("Widget Update: missing paginations: Back");
 
-                            (idx, cell, value)
-                        };
+                if cursor.is_over(back.bounds()) && self.page != 0 {
+                    self.change_page(table, self.page - 1, shell);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
 
-                        let target = {
-                            let alignment_offset = alignment_offset(
-                                cell_bounds.width,
-                                cell.min_width(),
-                                cell.horizontal_alignment(),
-                            );
+                let pages = children
+                    .next()
+                    .expect("Widget Update: missing paginations: Pages");
 
-                            cursor_position.x - cell_bounds.x - alignment_offset
-                        };
+                if cursor.is_over(pages.bounds()) {
+                    let Some(idx) = pages
+                        .children()
+                        .enumerate()
+                        .find(|(_, page)| cursor.is_over(page.bounds()))
+                        .map(|(idx, _)| idx)
+                    else {
+                        return event::Status::Ignored;
+                    };
 
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            mouse::Button::Left,
-                            self.last_click,
-                        );
+                    let (_, value) = self
+                        .paginations
+                        .get(idx)
+                        .expect("Widget Update: pages cells and layout not equal length");
 
-                        match click.kind() {
-                            click::Kind::Single => {
-                                let position = if target > 0.0 {
-                                    find_cursor_position(cell_bounds, &value, self, cell, target)
-                                } else {
-                                    None
-                                }
-                                .unwrap_or(0);
+                    if self.is_inert_page(table, idx) {
+                        return event::Status::Ignored;
+                    }
 
-                                if self.keyboard_modifiers.shift() {
-                                    self.cursor
-                                        .select_range(self.cursor.start(&value), position);
-                                } else {
-                                    self.cursor.move_to(position);
-                                }
+                    match value.parse::<usize>() {
+                        Ok(page) => {
+                            self.change_page(table, page.saturating_sub(1), shell);
+                        }
+                        Err(_) if value == PAGINATION_ELLIPSIS => {
+                            let Some(page) = self.ellipsis_target(table, idx) else {
+                                return event::Status::Ignored;
+                            };
 
-                                self.is_text_dragging = true;
-                            }
-                            click::Kind::Double => {
-                                let position =
-                                    find_cursor_position(cell_bounds, &value, self, cell, target)
-                                        .unwrap_or(0);
-                                let (start, end) = word_boundary(&value, position);
-                                self.cursor.select_range(start, end);
-                                self.is_text_dragging = false;
-                            }
-                            click::Kind::Triple => {
-                                self.cursor.select_all(&value);
-                                self.is_text_dragging = false;
-                            }
+                            self.change_page(table, page, shell);
                         }
+                        Err(_) if value.is_empty() => {
+                            self.change_page(table, 0, shell);
+                        }
+                        Err(_) => {}
+                    }
 
-                        self.last_click = Some(click);
-                        self.editing = Some(Editing::Cell {
-                            index: idx,
-                            value,
-                            is_header,
-                        });
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
 
-                        event::Status::Captured
-                    }
-                    None => {
-                        self.reset();
+                let next = children
+                    .next()
+                    .expect("Widget Update: missing paginations: Next");
 
-                        event::Status::Ignored
-                    }
+                if cursor.is_over(next.bounds()) && self.page < table.pages_end() {
+                    self.change_page(table, self.page + 1, shell);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
                 }
+
+                event::Status::Ignored
             }
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. })
-                if self.is_text_dragging =>
-            {
-                self.reset_resizing();
-                let Some(Editing::Cell {
-                    index,
-                    value,
-                    is_header,
-                }) = &self.editing
-                else {
-                    return event::Status::Ignored;
-                };
+            _ => event::Status::Ignored,
+        }
+    }
 
-                let mut children = layout.children();
-                let _numbering = children.next();
-                let headers = children
-                    .next()
-                    .expect("Widget Update: Missing header cells")
-                    .children();
-                let cells = children
-                    .next()
-                    .expect("Widget Update: Missing cells")
-                    .children();
+    /// Updates the goto input, or the search input if `is_search`.
+    #[allow(clippy::too_many_arguments)]
+    fn update_input<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        event: event::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        is_search: bool,
+    ) -> event::Status {
+        let font = table.font.unwrap_or_else(|| renderer.default_font());
 
-                let (bounds, cell) = if *is_header {
-                    let bounds = headers
-                        .enumerate()
-                        .find(|(idx, _)| *idx == *index)
-                        // Pair node
-                        .and_then(|(_, resize)| resize.children().next())
-                        // Label node
-                        .and_then(|pair| pair.children().next())
-                        .map(|label| label.bounds())
-                        .expect("Table Update: Editing selection header missing layout");
-                    let (cell, _) = &self.headers[*index];
-                    (bounds, cell)
-                } else {
-                    let bounds = cells
-                        .enumerate()
-                        .find(|(idx, _)| *idx == *index)
-                        .map(|(_, resize)| {
-                            resize
-                                .children()
-                                .next()
-                                .expect("Table Update: Editing resize node missing cell layout")
-                                .bounds()
-                        })
-                        .expect("Table Update: Editing selection missing layout");
-                    let cell = &self.cells[*index];
-                    (bounds, cell)
-                };
+        let (input, go) = if is_search {
+            (layout, None)
+        } else {
+            let mut children = layout.children();
+
+            let _ = children.next();
 
-                let target = {
-                    let alignment_offset = alignment_offset(
-                        bounds.width,
-                        cell.min_width(),
-                        cell.horizontal_alignment(),
-                    );
+            let input = children.next().expect("Widget Update: Missing Goto Input");
+            let go = children.next().expect("Widget Update: Missing Goto Go");
 
-                    position.x - bounds.x - alignment_offset
-                };
+            (input, Some(go))
+        };
 
-                let position = find_cursor_position(bounds, value, self, cell, target).unwrap_or(0);
+        let alignment = if is_search {
+            Horizontal::Left
+        } else {
+            Horizontal::Right
+        };
 
-                self.cursor.select_range(self.cursor.start(value), position);
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match cursor.position_over(input.bounds()) {
+                    Some(cursor_position) => {
+                        let target = {
+                            let input_bounds = input.bounds().shrink(self.pages_padding);
 
-                event::Status::Captured
-            }
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. })
-                if self.motion.is_some() =>
-            {
-                let Some(motion) = self.motion.as_mut() else {
-                    return event::Status::Ignored;
-                };
+                            let alignment_offset = alignment_offset(
+                                input_bounds.width,
+                                self.input(is_search).0.min_width(),
+                                alignment,
+                            );
 
-                let mut children = layout.children();
-                let numbering = children
-                    .next()
-                    .expect("Widget Update: Missing numbering cells")
-                    .children()
-                    .enumerate()
-                    .find_map(|(index, child)| {
-                        if child.bounds().contains(position) {
-                            Some(index)
-                        } else {
-                            None
-                        }
-                    });
-                let headers = children
-                    .next()
-                    .expect("Widget Update: Missing header cells")
-                    .children()
-                    .enumerate()
-                    .find_map(|(index, child)| {
-                        if child.bounds().contains(position) {
-                            Some(index)
-                        } else {
-                            None
-                        }
-                    });
+                            cursor_position.x - input_bounds.x - alignment_offset
+                        };
 
-                let cells = children
-                    .next()
-                    .expect("Widget Update: Missing Cells layout")
-                    .children()
-                    .enumerate()
-                    .find_map(|(index, child)| {
-                        if child.bounds().contains(position) {
-                            Some(index)
-                        } else {
-                            None
-                        }
-                    });
+                        let click = mouse::Click::new(
+                            cursor_position,
+                            mouse::Button::Left,
+                            self.last_click,
+                        );
 
-                match motion {
-                    Motion::Cell {
-                        d_column, d_row, ..
-                    } => {
-                        if let Some(numbering) = numbering {
-                            *d_row = numbering.wrapping_sub(1);
-                            return event::Status::Captured;
-                        }
+                        let is_starting = !is_search
+                            && !matches!(self.editing, Some(Editing::Goto(_)))
+                            && !self.keyboard_modifiers.shift();
 
-                        if let Some(header) = headers {
-                            *d_column = header;
-                            return event::Status::Captured;
-                        }
+                        match click.kind() {
+                            // Typing over the whole page number replaces it
+                            click::Kind::Single if is_starting => {
+                                self.cursor.select_all(&self.goto_input.1);
+                                self.is_text_dragging = false;
+                            }
+                            click::Kind::Single => {
+                                let position = if target > 0.0 {
+                                    let (cell, value) = self.input(is_search);
 
-                        if let Some(index) = cells {
-                            let (row, column) =
-                                (index % table.page_limit, index / table.page_limit);
-                            let row = row + (self.page * table.page_limit);
-                            *d_row = row;
-                            *d_column = column;
-                            return event::Status::Captured;
-                        }
-                    }
-                    Motion::Column { dst, .. } => {
-                        if let Some(index) = headers {
-                            *dst = index;
-                            return event::Status::Captured;
-                        }
+                                    find_cursor_position(
+                                        input.bounds().shrink(self.pages_padding),
+                                        value,
+                                        self,
+                                        cell,
+                                        target,
+                                        cursor_position.y
+                                            - input.bounds().y
+                                            - self.pages_padding.top,
+                                    )
+                                } else {
+                                    None
+                                }
+                                .unwrap_or(0);
 
-                        if let Some(cell) = cells {
-                            let column = cell / table.page_limit;
-                            *dst = column;
-                            return event::Status::Captured;
+                                if self.keyboard_modifiers.shift() {
+                                    let start = self.cursor.start(&self.input(is_search).1);
+                                    self.cursor.select_range(start, position);
+                                } else {
+                                    self.cursor.move_to(position);
+                                }
+                                self.is_text_dragging = true;
+                            }
+                            click::Kind::Double => {
+                                self.cursor.select_range(0, usize::MAX);
+
+                                self.is_text_dragging = false;
+                            }
+                            click::Kind::Triple => {
+                                let value = if is_search {
+                                    &self.search_input.1
+                                } else {
+                                    &self.goto_input.1
+                                };
+                                self.cursor.select_all(value);
+                                self.is_text_dragging = false;
+                            }
                         }
+
+                        let bounds = input.bounds().shrink(self.pages_padding);
+
+                        self.last_click = Some(click);
+                        self.editing = Some(if is_search {
+                            Editing::Search(bounds)
+                        } else {
+                            Editing::Goto(bounds)
+                        });
+
+                        event::Status::Captured
                     }
-                    Motion::Row { dst, .. } => {
-                        if let Some(index) = numbering {
-                            *dst = index.saturating_sub(1) + (self.page * table.page_limit);
+                    None => {
+                        if go.is_some_and(|go| cursor.is_over(go.bounds())) {
+                            self.submit_goto(table, shell);
+                            self.reset();
+                            shell.invalidate_layout();
                             return event::Status::Captured;
                         }
 
-                        if let Some(cell) = cells {
-                            let row = cell % table.page_limit;
-                            let row = row + (self.page * table.page_limit);
-                            *dst = row;
-                            return event::Status::Captured;
-                        }
+                        self.reset();
+                        event::Status::Ignored
                     }
                 }
-
-                event::Status::Ignored
             }
             Event::Mouse(mouse::Event::CursorMoved { position })
             | Event::Touch(touch::Event::FingerMoved { position, .. })
-                if self.resizing.is_some() =>
+                if self.is_text_dragging =>
             {
-                let Some(resize) = self.resizing.as_mut() else {
-                    return event::Status::Ignored;
-                };
-                let width = self.min_widths[resize.column];
-                let height = self.min_heights[resize.row];
-                let (new, diff) = resize.drag(position, width, height);
+                let text_bounds = input.bounds();
+
+                let target = {
+                    let alignment_offset = alignment_offset(
+                        text_bounds.width,
+                        self.input(is_search).0.raw().min_width(),
+                        alignment,
+                    );
 
-                self.min_widths[resize.column] = new.width;
-                self.min_heights[resize.row] = new.height;
+                    position.x - text_bounds.x - alignment_offset
+                };
 
-                if let Some(on_action) = table.on_action.as_ref() {
-                    let action = resize.action(new);
-                    let msg = on_action(action);
+                let (cell, value) = self.input(is_search);
 
-                    shell.publish(msg);
-                }
+                let position = find_cursor_position(
+                    text_bounds,
+                    value,
+                    self,
+                    cell,
+                    target,
+                    position.y - text_bounds.y,
+                )
+                .unwrap_or(0);
 
-                self.scroll_cells(scroll_bounds, diff * (1.0 / Self::SCROLL_MULT));
+                self.cursor.select_range(self.cursor.start(value), position);
 
-                shell.invalidate_layout();
                 event::Status::Captured
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) => {
@@ -2524,39 +6702,13 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
                     return event::Status::Ignored;
                 };
 
-                let Some(Editing::Cell {
-                    index,
-                    value,
-                    is_header,
-                    ..
-                }) = self.editing.as_mut()
-                else {
-                    return event::Status::Ignored;
-                };
-
-                let index = *index;
                 let modifiers = self.keyboard_modifiers;
                 focus.updated_at = Instant::now();
 
-                let (cell, col_kind, row, column) = if *is_header {
-                    let (cell, _) = &mut self.headers[index];
-                    let col = table
-                        .raw
-                        .column_kind(index)
-                        .expect("Cells update: Missing column in sheet");
-
-                    (cell, col, 0, index + 1)
+                let (cell, value) = if is_search {
+                    &mut self.search_input
                 } else {
-                    let cell = &mut self.cells[index];
-                    let (row, column) = (index % table.page_limit, index / table.page_limit);
-                    let row = row + (self.page * table.page_limit);
-
-                    let col = table
-                        .raw
-                        .column_kind(column)
-                        .expect("Cells update: Missing column in sheet");
-
-                    (cell, col, row, column)
+                    &mut self.goto_input
                 };
 
                 if key.as_ref() == keyboard::Key::Character("a") && modifiers.command() {
@@ -2564,973 +6716,1218 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
                     return event::Status::Captured;
                 }
 
-                match text {
-                    Some(text) if *is_header => {
-                        if let Some(c) = text.chars().next().filter(|c| !c.is_control()) {
-                            let mut editor = Editor::new(value, &mut self.cursor);
-                            editor.insert(c);
-
-                            cell.update(super::text::<Renderer>(
-                                value,
-                                Self::MAX_CELL,
-                                header_font,
-                                cell.horizontal_alignment(),
-                                size,
-                            ));
-
-                            focus.updated_at = Instant::now();
+                if key.as_ref() == keyboard::Key::Character("c") && modifiers.command() {
+                    if let Some(selected) = self.cursor.selected(value) {
+                        clipboard.write(advanced::clipboard::Kind::Standard, selected.to_owned());
+                    }
 
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action =
-                                    Action::header_input(value.clone(), column.saturating_sub(1));
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
+                    return event::Status::Captured;
+                }
 
-                            let min_bounds = cell.min_bounds().expand(padding);
-                            let bounds = Size::new(self.min_widths[column], self.min_heights[row]);
+                let is_cut = key.as_ref() == keyboard::Key::Character("x") && modifiers.command();
+                let is_paste = key.as_ref() == keyboard::Key::Character("v") && modifiers.command();
+
+                if is_cut || is_paste {
+                    let mut editor = Editor::new(value, &mut self.cursor);
+                    let is_changed = if is_cut {
+                        editor
+                            .cut()
+                            .map(|selected| {
+                                clipboard.write(advanced::clipboard::Kind::Standard, selected)
+                            })
+                            .is_some()
+                    } else {
+                        let contents = clipboard
+                            .read(advanced::clipboard::Kind::Standard)
+                            .unwrap_or_default();
+
+                        editor.insert_str(
+                            &contents,
+                            |c| !c.is_control() && (is_search || c.is_ascii_digit()),
+                            |_| true,
+                        )
+                    };
 
-                            if min_bounds.width > bounds.width {
-                                self.min_widths[column] = min_bounds.width;
-                                self.min_heights[row] = min_bounds.height;
-                                shell.invalidate_layout();
-                            }
+                    if is_changed {
+                        cell.update(super::text::<Renderer>(
+                            value,
+                            self.max_cell,
+                            font,
+                            alignment,
+                            self.page_size,
+                            self.shaping,
+                            self.line_height,
+                        ));
 
-                            return event::Status::Captured;
+                        if is_search {
+                            self.search(table, shell);
                         }
                     }
-                    Some(text) => {
-                        if let Some(c) = text
-                            .chars()
-                            .next()
-                            .filter(|c| !c.is_control() && table.raw.column_filter(&col_kind, *c))
-                        {
-                            let mut editor = Editor::new(value, &mut self.cursor);
-                            editor.insert(c);
 
-                            cell.update(super::text::<Renderer>(
-                                value,
-                                Self::MAX_CELL,
-                                font,
-                                cell.horizontal_alignment(),
-                                size,
-                            ));
+                    return event::Status::Captured;
+                }
+
+                if let Some(text) = text {
+                    if let Some(c) = text
+                        .chars()
+                        .next()
+                        .filter(|c| !c.is_control() && (is_search || c.is_ascii_digit()))
+                    {
+                        let mut editor = Editor::new(value, &mut self.cursor);
 
-                            focus.updated_at = Instant::now();
+                        editor.insert(c);
 
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action = Action::cell_input(value.clone(), column, row);
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
+                        cell.update(super::text::<Renderer>(
+                            value,
+                            self.max_cell,
+                            font,
+                            alignment,
+                            self.page_size,
+                            self.shaping,
+                            self.line_height,
+                        ));
 
-                            let column = column + 1;
-                            let row = (index % table.page_limit) + 1;
-                            let min_bounds = cell.min_bounds().expand(padding);
-                            let bounds = Size::new(self.min_widths[column], self.min_heights[row]);
+                        focus.updated_at = Instant::now();
 
-                            if min_bounds.width > bounds.width || min_bounds.height > bounds.height
-                            {
-                                self.min_widths[column] = min_bounds.width;
-                                self.min_heights[row] = min_bounds.height;
-                                shell.invalidate_layout();
-                            }
-                            return event::Status::Captured;
+                        if is_search {
+                            self.search(table, shell);
                         }
+
+                        return event::Status::Captured;
                     }
-                    None => {}
                 }
 
                 match key.as_ref() {
                     keyboard::Key::Named(keyboard::key::Named::Enter) => {
-                        if *is_header {
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action =
-                                    Action::header_submit(value.clone(), column.saturating_sub(1));
-                                let msg = on_action(action);
-                                shell.publish(msg)
-                            }
-                        } else if let Some(on_action) = table.on_action.as_ref() {
-                            let action = Action::cell_submit(value.clone(), column, row);
-                            let msg = on_action(action);
-                            shell.publish(msg);
+                        if !is_search {
+                            self.submit_goto(table, shell);
                         }
 
                         self.reset();
                         shell.invalidate_layout();
-                        event::Status::Captured
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::Backspace) => {
                         let mut editor = Editor::new(value, &mut self.cursor);
-                        editor.backspace();
+
+                        if modifiers.command() {
+                            editor.backspace_word();
+                        } else {
+                            editor.backspace();
+                        }
 
                         cell.update(super::text::<Renderer>(
                             value,
-                            Self::MAX_CELL,
-                            if *is_header { header_font } else { font },
-                            cell.horizontal_alignment(),
-                            size,
+                            self.max_cell,
+                            font,
+                            alignment,
+                            self.page_size,
+                            self.shaping,
+                            self.line_height,
                         ));
 
-                        if *is_header {
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action =
-                                    Action::header_input(value.clone(), column.saturating_sub(1));
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        } else if let Some(on_action) = table.on_action.as_ref() {
-                            let action = Action::cell_input(value.clone(), column, row);
-                            let msg = on_action(action);
-                            shell.publish(msg)
+                        if is_search {
+                            self.search(table, shell);
                         }
 
-                        event::Status::Captured
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::Delete) => {
                         let mut editor = Editor::new(value, &mut self.cursor);
-                        editor.delete();
+
+                        if modifiers.command() {
+                            editor.delete_word();
+                        } else {
+                            editor.delete();
+                        }
 
                         cell.update(super::text::<Renderer>(
                             value,
-                            Self::MAX_CELL,
-                            if *is_header { header_font } else { font },
-                            cell.horizontal_alignment(),
-                            size,
+                            self.max_cell,
+                            font,
+                            alignment,
+                            self.page_size,
+                            self.shaping,
+                            self.line_height,
                         ));
 
-                        if *is_header {
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action =
-                                    Action::header_input(value.clone(), column.saturating_sub(1));
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        } else if let Some(on_action) = table.on_action.as_ref() {
-                            let action = Action::cell_input(value.clone(), column, row);
-                            let msg = on_action(action);
-                            shell.publish(msg)
+                        if is_search {
+                            self.search(table, shell);
                         }
 
-                        event::Status::Captured
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
-                        if modifiers.shift() {
-                            self.cursor.select_left(value);
-                        } else {
-                            self.cursor.move_left(value);
+                        match (modifiers.shift(), modifiers.command()) {
+                            (true, true) => self.cursor.select_word_left(value),
+                            (true, false) => self.cursor.select_left(value),
+                            (false, true) => self.cursor.move_word_left(value),
+                            (false, false) => self.cursor.move_left(value),
                         }
-
-                        event::Status::Captured
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
-                        if modifiers.shift() {
-                            self.cursor.select_right(value);
-                        } else {
-                            self.cursor.move_right(value);
+                        match (modifiers.shift(), modifiers.command()) {
+                            (true, true) => self.cursor.select_word_right(value),
+                            (true, false) => self.cursor.select_right(value),
+                            (false, true) => self.cursor.move_word_right(value),
+                            (false, false) => self.cursor.move_right(value),
                         }
-
-                        event::Status::Captured
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::Escape) => {
-                        self.reset();
-                        event::Status::Captured
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
-                        if modifiers.shift() {
-                            self.cursor.select_to_start(value);
+                        if is_search {
+                            value.clear();
+                            self.search(table, shell);
                         } else {
-                            self.cursor.move_to(0);
+                            *value = (self.page + 1).to_string();
                         }
 
-                        event::Status::Captured
+                        self.reset();
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                        self.cursor.move_to(0);
+                        return event::Status::Captured;
                     }
                     keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
-                        if modifiers.shift() {
-                            self.cursor.select_to_end(value);
-                        } else {
-                            self.cursor.move_to_end(value);
-                        }
-
-                        event::Status::Captured
+                        self.cursor.move_to_end(value);
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Tab) => {
+                        return event::Status::Ignored;
                     }
-                    keyboard::Key::Named(keyboard::key::Named::Tab) => event::Status::Ignored,
 
-                    _ => event::Status::Captured,
+                    _ => {}
                 }
+
+                event::Status::Captured
             }
             _ => event::Status::Ignored,
         }
     }
 
-    fn update_pagination<Raw: RawTable, Message, Theme: Catalog>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_update<Raw: RawTable, Message, Theme: Catalog>(
         &mut self,
         table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
         event: event::Event,
         layout: layout::Layout<'_>,
         cursor: mouse::Cursor,
+        clipboard: &mut dyn advanced::Clipboard,
         shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
     ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mut children = layout.children();
+        // Edits dropped by operations or layouts are only noticed here
+        self.publish_edit_cancel(table, shell);
 
-                let back = children
-                    .next()
-                    .expect("Widget Update: missing paginations: Back");
+        let status = self.update(
+            table, renderer, event, layout, cursor, clipboard, shell, viewport,
+        );
 
-                if cursor.is_over(back.bounds()) && self.page != 0 {
-                    let previous = self.page;
-                    self.page -= 1;
-                    self.goto_input.1 = (self.page + 1).to_string();
+        self.publish_edit_cancel(table, shell);
+        self.publish_edit_state(table, shell);
+        status
+    }
 
-                    if let Some(on_action) = table.on_action.as_ref() {
-                        let action = Action::page(previous, self.page);
-                        let msg = on_action(action);
-                        shell.publish(msg);
-                    }
+    /// Returns the [`CellTarget`] of the header or data cell being edited.
+    fn edit_target<Raw: RawTable, Message, Theme: Catalog>(
+        &self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+    ) -> Option<CellTarget> {
+        let Some(Editing::Cell {
+            index, is_header, ..
+        }) = self.editing.as_ref()
+        else {
+            return None;
+        };
 
-                    shell.invalidate_layout();
-                    return event::Status::Captured;
-                }
+        let target = if *is_header {
+            CellTarget {
+                row: 0,
+                column: *index,
+                kind: TargetKind::Header,
+            }
+        } else {
+            CellTarget {
+                row: index % table.page_limit + self.page * table.page_limit,
+                column: index / table.page_limit,
+                kind: TargetKind::Cell,
+            }
+        };
 
-                let pages = children
-                    .next()
-                    .expect("Widget Update: missing paginations: Pages");
+        Some(target)
+    }
 
-                if cursor.is_over(pages.bounds()) {
-                    let Some(idx) = pages
-                        .children()
-                        .enumerate()
-                        .find(|(_, page)| cursor.is_over(page.bounds()))
-                        .map(|(idx, _)| idx)
-                    else {
-                        return event::Status::Ignored;
-                    };
+    /// Publishes an [`Action::EditCancel`] if the edit of the last event was
+    /// discarded without being submitted.
+    fn publish_edit_cancel<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let target = self.edit_target(table);
 
-                    let (_, value) = self
-                        .paginations
-                        .get(idx)
-                        .expect("Widget Update: pages cells and layout not equal length");
+        if let Some(edited) = self.edited.filter(|edited| Some(*edited) != target) {
+            if let Some(on_action) = table.on_action.as_ref() {
+                shell.publish(on_action(Action::EditCancel(edited)));
+            }
+        }
 
-                    match value.parse::<usize>() {
-                        Ok(page) => {
-                            let previous = self.page;
-                            self.page = page - 1;
+        self.edited = target;
+    }
 
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action = Action::page(previous, self.page);
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        }
-                        Err(_) if value == PAGINATION_ELLIPSIS => {
-                            let (_, left) = &self.paginations[idx - 1];
-                            let (_, right) = &self.paginations[idx + 1];
+    /// Publishes an [`Action::EditState`] if the value or cursor of the
+    /// header or data cell being edited changed.
+    fn publish_edit_state<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (Some(target), Some(Editing::Cell { value, .. })) =
+            (self.edit_target(table), self.editing.as_ref())
+        else {
+            self.edit_state = None;
+            return;
+        };
+
+        let cursor = match self.cursor.state(value) {
+            utils::State::Index(index) => index..index,
+            utils::State::Selection { start, end } => start..end,
+        };
+
+        let is_changed = self.edit_state.as_ref().is_none_or(|state| {
+            state.target != target || state.cursor != cursor || state.value != *value
+        });
+
+        if !is_changed {
+            return;
+        }
+
+        let state = EditState {
+            target,
+            value: value.clone(),
+            cursor,
+        };
+
+        if let Some(on_action) = table.on_action.as_ref() {
+            shell.publish(on_action(Action::EditState(state.clone())));
+        }
+
+        self.edit_state = Some(state);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn update<Raw: RawTable, Message, Theme: Catalog>(
+        &mut self,
+        table: &Table<'_, Raw, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        event: event::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        clipboard: &mut dyn advanced::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let padding = table.padding;
+
+        self.cursor_position = cursor.position_over(layout.bounds());
+
+        // Operations cannot relayout, so the first event after one does
+        if self.is_stale {
+            self.is_stale = false;
+            shell.invalidate_layout();
+        }
+
+        if table.loading {
+            return event::Status::Ignored;
+        }
+
+        // Any input hides the tooltip
+        if !matches!(event, Event::Window(_)) {
+            self.tooltip = None;
+            self.tooltip_hover = None;
+        }
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let cells = children
+            .next()
+            .expect("Widget Update: Missing cells layout");
+
+        // The status takes no input
+        let _status = children
+            .next()
+            .expect("Widget Update: Missing status layout");
+
+        let pagination = children
+            .next()
+            .expect("Widget Update: Missing pagination layout");
+
+        let goto = children.next().expect("Widget Update: Missing goto layout");
+        let sizes = children
+            .next()
+            .expect("Widget Update: Missing page sizes layout");
+        let footer = children
+            .next()
+            .expect("Widget Update: Missing footer layout");
+        let search = children
+            .next()
+            .expect("Widget Update: Missing search layout");
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if cursor.is_over(cells.bounds()) && !cursor.is_over(footer.bounds()) =>
+            {
+                self.focus();
+
+                let cells_viewport = self.cells_viewport(bounds, padding, cells);
+                let is_over_scrollbar = self
+                    .scrollbars(table, cells_viewport)
+                    .into_iter()
+                    .flatten()
+                    .any(|scrollbar| cursor.is_over(scrollbar.track));
+
+                if is_over_scrollbar {
+                    return event::Status::Captured;
+                }
+
+                return self.update_cells_right_click(table, cells, cursor, shell);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.is_focused = if cursor.is_over(layout.bounds()) {
+                    self.is_focused.or_else(|| {
+                        let now = Instant::now();
+
+                        Some(Focus {
+                            updated_at: now,
+                            now,
+                            is_window_focused: true,
+                        })
+                    })
+                } else {
+                    None
+                };
 
-                            let left = left.parse::<usize>().expect("No way this fails");
-                            let right = right.parse::<usize>().expect("No way this fails");
+                // The footer only shows values
+                if cursor.is_over(footer.bounds()) {
+                    return event::Status::Captured;
+                }
 
-                            let page = left + (right - left) / 2;
+                let cells_viewport = self.cells_viewport(bounds, padding, cells);
 
-                            let previous = self.page;
-                            self.page = page;
+                for scrollbar in self.scrollbars(table, cells_viewport).into_iter().flatten() {
+                    let Some(position) = cursor.position_over(scrollbar.track) else {
+                        continue;
+                    };
+                    let position = scrollbar.position(position);
 
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action = Action::page(previous, self.page);
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        }
-                        Err(_) if value.is_empty() => {
-                            let previous = self.page;
-                            self.page = 0;
+                    if cursor.is_over(scrollbar.thumb) {
+                        let grab = position - scrollbar.thumb_start();
+                        self.scrollbar_drag = Some((scrollbar.is_vertical, grab));
+                    } else {
+                        // Clicking the track pages towards the cursor
+                        let (view, axis) = if scrollbar.is_vertical {
+                            (cells_viewport.height, Vector::new(0.0, 1.0))
+                        } else {
+                            (cells_viewport.width, Vector::new(1.0, 0.0))
+                        };
+                        let direction = if position < scrollbar.thumb_start() {
+                            1.0
+                        } else {
+                            -1.0
+                        };
 
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action = Action::page(previous, self.page);
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        }
-                        Err(_) => {}
+                        self.scroll_cells(cells_viewport.size(), axis * (direction * view));
                     }
 
-                    self.goto_input.1 = (self.page + 1).to_string();
                     shell.invalidate_layout();
                     return event::Status::Captured;
                 }
 
-                let next = children
-                    .next()
-                    .expect("Widget Update: missing paginations: Next");
-
-                if cursor.is_over(next.bounds()) && self.page < table.pages_end() {
-                    let previous = self.page;
-                    self.page += 1;
-                    self.goto_input.1 = (self.page + 1).to_string();
-
-                    if let Some(on_action) = table.on_action.as_ref() {
-                        let action = Action::page(previous, self.page);
-                        let msg = on_action(action);
-                        shell.publish(msg);
+                // Fingers tap the cells as the mouse clicks them, until they pan
+                if let Event::Touch(touch::Event::FingerPressed { id, position }) = event {
+                    if cells_viewport.contains(position) {
+                        self.touch_pan = Some(TouchPan::new(id, position));
                     }
-
-                    shell.invalidate_layout();
-                    return event::Status::Captured;
                 }
 
-                event::Status::Ignored
-            }
-            _ => event::Status::Ignored,
-        }
-    }
+                if cursor.is_over(cells.bounds()) {
+                    let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+                    return self.update_cells(
+                        table,
+                        renderer,
+                        event,
+                        cells,
+                        cursor,
+                        clipboard,
+                        shell,
+                        scroll_bounds,
+                    );
+                }
 
-    fn update_goto<Raw: RawTable, Message, Theme: Catalog>(
-        &mut self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &Renderer,
-        event: event::Event,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-        shell: &mut Shell<'_, Message>,
-    ) -> event::Status {
-        let font = table.font.unwrap_or_else(|| renderer.default_font());
+                if cursor.is_over(pagination.bounds()) && table.multiple_pages() {
+                    if table.submit_on_page_change {
+                        self.submit_editing(table, shell);
+                    }
 
-        let mut children = layout.children();
+                    self.reset();
+                    return self.update_pagination(table, event, pagination, cursor, shell);
+                }
 
-        let _ = children.next();
+                if cursor.is_over(goto.bounds()) && table.multiple_pages() {
+                    if table.submit_on_page_change {
+                        self.submit_editing(table, shell);
+                    }
 
-        let input = children.next().expect("Widget Update: Missing Goto Input");
-        let go = children.next().expect("Widget Update: Missing Goto Go");
+                    return self.update_input(
+                        table, renderer, event, goto, cursor, clipboard, shell, false,
+                    );
+                }
 
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                match cursor.position_over(input.bounds()) {
-                    Some(cursor_position) => {
-                        let target = {
-                            let input_bounds = input.bounds().shrink(self.pages_padding);
+                if cursor.is_over(search.bounds()) && table.searchable {
+                    return self.update_input(
+                        table, renderer, event, search, cursor, clipboard, shell, true,
+                    );
+                }
 
-                            let alignment_offset = alignment_offset(
-                                input_bounds.width,
-                                self.goto_input.0.min_width(),
-                                Horizontal::Right,
-                            );
+                if cursor.is_over(sizes.bounds()) && table.shows_page_sizes() {
+                    self.reset();
+                    return self.update_page_sizes(table, sizes, cursor, shell);
+                }
 
-                            cursor_position.x - input_bounds.x - alignment_offset
-                        };
+                self.submit_editing(table, shell);
+                self.reset();
+                return event::Status::Ignored;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.is_text_dragging = false;
+                self.scrollbar_drag = None;
+                self.selection_drag = None;
+                self.touch_pan = None;
+                self.auto_scroll = Vector::ZERO;
 
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            mouse::Button::Left,
-                            self.last_click,
-                        );
+                if let Some(resize) = self.resizing.take() {
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let size =
+                            Size::new(self.min_widths[resize.column], self.min_heights[resize.row]);
+                        let action = resize.action(size, self.page * table.page_limit);
+                        let msg = on_action(action);
+                        shell.publish(msg);
+                    }
+                }
 
-                        match click.kind() {
-                            click::Kind::Single => {
-                                let position = if target > 0.0 {
-                                    let value = &self.goto_input.1;
+                if let Some(motion) = self.motion.take() {
+                    if let Some(selection) = self.selection.as_mut() {
+                        selection.update(motion)
+                    }
 
-                                    find_cursor_position(
-                                        input.bounds().shrink(self.pages_padding),
-                                        value,
-                                        self,
-                                        &self.goto_input.0,
-                                        target,
-                                    )
-                                } else {
-                                    None
-                                }
-                                .unwrap_or(0);
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        self.swap_dimensions(motion, table.page_limit);
+                        let msg = on_action(Action::MoveSelection(motion));
+                        shell.publish(msg);
 
-                                if self.keyboard_modifiers.shift() {
-                                    self.cursor.select_range(
-                                        self.cursor.start(&self.goto_input.1),
-                                        position,
-                                    );
-                                } else {
-                                    self.cursor.move_to(position);
-                                }
-                                self.is_text_dragging = true;
-                            }
-                            click::Kind::Double => {
-                                self.cursor.select_range(0, usize::MAX);
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.scrollbar_drag.is_some() =>
+            {
+                let Some((is_vertical, grab)) = self.scrollbar_drag else {
+                    return event::Status::Ignored;
+                };
 
-                                self.is_text_dragging = false;
-                            }
-                            click::Kind::Triple => {
-                                self.cursor.select_all(&self.goto_input.1);
-                                self.is_text_dragging = false;
-                            }
-                        }
+                let cells_viewport = self.cells_viewport(bounds, padding, cells);
+                let scrollbar = self
+                    .scrollbars(table, cells_viewport)
+                    .into_iter()
+                    .flatten()
+                    .find(|scrollbar| scrollbar.is_vertical == is_vertical);
 
-                        self.last_click = Some(click);
-                        self.editing =
-                            Some(Editing::Goto(input.bounds().shrink(self.pages_padding)));
+                if let Some(scrollbar) = scrollbar {
+                    let offset = scrollbar.offset(scrollbar.position(*position) - grab);
 
-                        event::Status::Captured
+                    if is_vertical {
+                        self.scroll_offset.y = offset;
+                    } else {
+                        self.scroll_offset.x = offset;
                     }
-                    None => {
-                        self.reset();
 
-                        if cursor.is_over(go.bounds()) {
-                            let (_, page) = &self.goto_input;
-                            match page.parse::<usize>() {
-                                Ok(page) => {
-                                    let previous = self.page;
-                                    self.page = usize::clamp(page - 1, 0, table.pages_end());
-                                    if previous != self.page {
-                                        if let Some(on_action) = table.on_action.as_ref() {
-                                            let action = Action::page(previous, self.page);
-                                            let msg = on_action(action);
-                                            shell.publish(msg);
-                                        }
-                                    }
-                                    shell.invalidate_layout();
-                                    return event::Status::Captured;
-                                }
-                                Err(_) if page.is_empty() => {
-                                    self.page = 0;
-                                    shell.invalidate_layout();
-                                    return event::Status::Captured;
-                                }
-                                _ => {}
-                            }
-                        }
+                    shell.invalidate_layout();
+                }
 
-                        event::Status::Ignored
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.is_text_dragging =>
+            {
+                match self.editing {
+                    Some(Editing::Goto(_)) => {
+                        return self.update_input(
+                            table, renderer, event, goto, cursor, clipboard, shell, false,
+                        );
+                    }
+                    Some(Editing::Search(_)) => {
+                        return self.update_input(
+                            table, renderer, event, search, cursor, clipboard, shell, true,
+                        );
                     }
+                    Some(Editing::Cell { .. }) => {
+                        let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+                        return self.update_cells(
+                            table,
+                            renderer,
+                            event,
+                            cells,
+                            cursor,
+                            clipboard,
+                            shell,
+                            scroll_bounds,
+                        );
+                    }
+                    None => {}
                 }
             }
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. })
-                if self.is_text_dragging =>
+            Event::Touch(touch::Event::FingerMoved { id, position })
+                if self.touch_pan.is_some_and(|pan| pan.finger == *id)
+                    && self.motion.is_none()
+                    && self.resizing.is_none() =>
             {
-                let text_bounds = input.bounds();
+                let Some(delta) = self.touch_pan.as_mut().and_then(|pan| pan.pan(*position)) else {
+                    return event::Status::Captured;
+                };
 
-                let target = {
-                    let alignment_offset = alignment_offset(
-                        text_bounds.width,
-                        self.goto_input.0.raw().min_width(),
-                        Horizontal::Right,
-                    );
+                // Panning takes over from the selection started by the tap
+                self.selection_drag = None;
+                self.auto_scroll = Vector::ZERO;
 
-                    position.x - text_bounds.x - alignment_offset
-                };
+                let viewport = self.cells_viewport(bounds, padding, cells).size();
+                self.scroll_cells(viewport, delta);
+
+                shell.invalidate_layout();
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.selection_drag.is_some() =>
+            {
+                let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+                return self.update_cells(
+                    table,
+                    renderer,
+                    event,
+                    cells,
+                    cursor,
+                    clipboard,
+                    shell,
+                    scroll_bounds,
+                );
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.motion.is_some() =>
+            {
+                let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+                return self.update_cells(
+                    table,
+                    renderer,
+                    event,
+                    cells,
+                    cursor,
+                    clipboard,
+                    shell,
+                    scroll_bounds,
+                );
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if self.resizing.is_some() =>
+            {
+                let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+                return self.update_cells(
+                    table,
+                    renderer,
+                    event,
+                    cells,
+                    cursor,
+                    clipboard,
+                    shell,
+                    scroll_bounds,
+                );
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let font = table.font.unwrap_or_else(|| renderer.default_font());
+                let size = table.text_size.unwrap_or_else(|| renderer.default_size());
 
-                let (cell, value) = &self.goto_input;
+                let viewport = self.cells_viewport(bounds, padding, cells);
 
-                let position =
-                    find_cursor_position(text_bounds, value, self, cell, target).unwrap_or(0);
+                self.hover_overflow(table, cells, cursor, font, size);
+                self.hover_tooltip(table, cells, cursor, shell);
+                self.hover_cell(table, viewport, cells, cursor, shell);
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if table.multiple_pages() && cursor.is_over(pagination.bounds()) =>
+            {
+                let steps = match *delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        self.pages_scroll = 0.0;
+
+                        if y < 0.0 {
+                            1
+                        } else if y > 0.0 {
+                            -1
+                        } else {
+                            0
+                        }
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        self.pages_scroll += y;
 
-                self.cursor.select_range(self.cursor.start(value), position);
+                        let steps = (self.pages_scroll / Self::PAGES_SCROLL_STEP).trunc();
+                        self.pages_scroll -= steps * Self::PAGES_SCROLL_STEP;
+
+                        -steps as isize
+                    }
+                };
+
+                if steps != 0 {
+                    let page = self.page.saturating_add_signed(steps);
+                    self.change_page(table, page, shell);
+                    shell.invalidate_layout();
+                }
 
-                event::Status::Captured
+                return event::Status::Captured;
             }
-            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) => {
-                let Some(focus) = self.is_focused.as_mut() else {
-                    return event::Status::Ignored;
-                };
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match *delta {
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                    mouse::ScrollDelta::Lines { x, y } => Vector::new(x, y) * Self::WHEEL_LINE,
+                } * table.scroll_step;
 
-                let modifiers = self.keyboard_modifiers;
-                focus.updated_at = Instant::now();
+                // Shift turns vertical scrolling horizontal. Some platforms
+                // already do this, in which case x is set.
+                let delta = if self.keyboard_modifiers.shift() && delta.x == 0.0 {
+                    Vector::new(delta.y, 0.0)
+                } else {
+                    delta
+                };
 
-                let (cell, value) = &mut self.goto_input;
+                let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
 
-                if key.as_ref() == keyboard::Key::Character("a") && modifiers.command() {
-                    self.cursor.select_all(value);
+                let Some((duration, easing)) = table.smooth_scrolling else {
+                    self.scroll_cells(scroll_bounds, delta);
+                    shell.invalidate_layout();
                     return event::Status::Captured;
-                }
-
-                if let Some(text) = text {
-                    if let Some(c) = text
-                        .chars()
-                        .next()
-                        .filter(|c| !c.is_control() && c.is_ascii_digit())
-                    {
-                        let mut editor = Editor::new(value, &mut self.cursor);
+                };
 
-                        editor.insert(c);
+                // Offsets scrolled by anything else start the animation over
+                let mut scroll = self
+                    .smooth_scroll
+                    .take()
+                    .filter(|scroll| scroll.applied == self.scroll_offset)
+                    .unwrap_or_else(|| SmoothScroll::new(self.scroll_offset, duration, easing));
 
-                        let pages_end = table.raw.height() / table.page_limit;
-                        match value.parse::<usize>() {
-                            Ok(page) if page > pages_end => *value = (pages_end + 1).to_string(),
-                            Err(_) if value.is_empty() => {
-                                *value = (self.page + 1).to_string();
-                            }
-                            _ => {}
-                        }
+                let target = self.clamp_scroll(scroll_bounds, scroll.target + delta);
+                scroll.scroll_to(target, Instant::now());
+                self.smooth_scroll = Some(scroll);
 
-                        cell.update(super::text::<Renderer>(
-                            value,
-                            Self::MAX_CELL,
-                            font,
-                            Horizontal::Right,
-                            self.page_size,
-                        ));
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                return event::Status::Captured;
+            }
 
-                        focus.updated_at = Instant::now();
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if self.editing.is_none() && self.is_focused.is_some() => {
+                if let Some(callback) = table.on_keypress.as_ref() {
+                    let msg = callback(KeyPress {
+                        key: key.clone(),
+                        modifiers: *modifiers,
+                        text: text.as_ref().map(|text| text.to_string()),
+                    });
 
-                        return event::Status::Captured;
+                    if let Some(msg) = msg {
+                        shell.publish(msg);
+                        return event::Status::Ignored;
                     }
                 }
 
-                match key.as_ref() {
-                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
-                        if let Ok(page) = value.parse::<usize>() {
-                            let page = if page == 0 { 0 } else { page - 1 };
-
-                            let previous = self.page;
+                if key.as_ref() == keyboard::Key::Character("a") && modifiers.command() {
+                    if table.rows == 0 || table.cols == 0 {
+                        return event::Status::Captured;
+                    }
 
-                            self.page = usize::clamp(page, 0, table.pages_end());
+                    let column_end = table.cols.saturating_sub(1);
 
-                            if previous != self.page {
-                                if let Some(on_action) = table.on_action.as_ref() {
-                                    let action = Action::page(previous, self.page);
-                                    let msg = on_action(action);
-                                    shell.publish(msg);
-                                }
-                            }
+                    let page = Selection::row_range(self.page_rows(table), column_end);
 
-                            self.reset();
-                            shell.invalidate_layout();
-                            return event::Status::Captured;
-                        } else if value.is_empty() {
-                            *value = (self.page + 1).to_string();
+                    // A second Ctrl + A extends the selection to every page
+                    let selection = if self.selection.as_ref() == Some(&page) {
+                        Selection::row_range(0..=table.rows.saturating_sub(1), column_end)
+                    } else {
+                        page
+                    };
 
-                            self.reset();
-                            shell.invalidate_layout();
-                            return event::Status::Captured;
-                        }
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
-                        let mut editor = Editor::new(value, &mut self.cursor);
-                        editor.backspace();
-                        cell.update(super::text::<Renderer>(
-                            value,
-                            Self::MAX_CELL,
-                            font,
-                            Horizontal::Right,
-                            self.page_size,
-                        ));
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::Delete) => {
-                        let mut editor = Editor::new(value, &mut self.cursor);
-                        editor.delete();
-                        cell.update(super::text::<Renderer>(
-                            value,
-                            Self::MAX_CELL,
-                            font,
-                            Horizontal::Right,
-                            self.page_size,
-                        ));
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
-                        if modifiers.shift() {
-                            self.cursor.select_left(value)
-                        } else {
-                            self.cursor.move_left(value)
-                        }
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
-                        if modifiers.shift() {
-                            self.cursor.select_right(value)
-                        } else {
-                            self.cursor.move_right(value)
-                        }
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
-                        self.reset();
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
-                        self.cursor.move_to(0);
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
-                        self.cursor.move_to_end(value);
-                        return event::Status::Captured;
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::Tab) => {
-                        return event::Status::Ignored;
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let action = Action::Selection(selection.clone());
+                        let msg = on_action(action);
+                        shell.publish(msg);
                     }
 
-                    _ => {}
+                    self.selection = Some(selection);
+                    self.motion = None;
+                    return event::Status::Captured;
                 }
 
-                event::Status::Captured
-            }
-            _ => event::Status::Ignored,
-        }
-    }
-
-    pub fn on_update<Raw: RawTable, Message, Theme: Catalog>(
-        &mut self,
-        table: &Table<'_, Raw, Message, Theme, Renderer>,
-        renderer: &Renderer,
-        event: event::Event,
-        layout: layout::Layout<'_>,
-        cursor: mouse::Cursor,
-        shell: &mut Shell<'_, Message>,
-    ) -> event::Status {
-        let padding = table.padding;
-        let spacing = table.spacing;
-
-        self.cursor_position = cursor.position_over(layout.bounds());
-
-        let bounds = layout.bounds();
-        let mut children = layout.children();
+                if matches!(key, keyboard::Key::Named(keyboard::key::Named::Escape))
+                    && self.selection.is_some()
+                {
+                    self.reset_selection();
+                    self.motion = None;
 
-        let cells = children
-            .next()
-            .expect("Widget Update: Missing cells layout");
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let msg = on_action(Action::SelectionClear);
+                        shell.publish(msg);
+                    }
 
-        let status = children
-            .next()
-            .expect("Widget Update: Missing status layout");
+                    return event::Status::Captured;
+                }
 
-        let pagination = children
-            .next()
-            .expect("Widget Update: Missing pagination layout");
+                if key.as_ref() == keyboard::Key::Character("c") && modifiers.command() {
+                    if let Some(selection) = self.selection.as_ref() {
+                        clipboard.write(
+                            advanced::clipboard::Kind::Standard,
+                            selection.copy(table.raw),
+                        );
+                        return event::Status::Captured;
+                    }
+                }
 
-        let goto = children.next().expect("Widget Update: Missing goto layout");
+                if key.as_ref() == keyboard::Key::Character("x") && modifiers.command() {
+                    if let Some(selection) = self.selection.as_ref() {
+                        clipboard.write(
+                            advanced::clipboard::Kind::Standard,
+                            selection.copy(table.raw),
+                        );
 
-        match &event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                self.is_focused = if cursor.is_over(layout.bounds()) {
-                    self.is_focused.or_else(|| {
-                        let now = Instant::now();
+                        // Read-only tables are only copied from
+                        if table.read_only {
+                            return event::Status::Captured;
+                        }
 
-                        Some(Focus {
-                            updated_at: now,
-                            now,
-                            is_window_focused: true,
-                        })
-                    })
-                } else {
-                    None
-                };
+                        let notice = match self.clear_selected(table, shell) {
+                            1 => "1 cell cut".to_owned(),
+                            len => format!("{len} cells cut"),
+                        };
+                        self.notify(notice, shell);
 
-                if cursor.is_over(cells.bounds()) {
-                    let mut cells_children = cells.children();
-                    let numbering = cells_children
-                        .next()
-                        .expect("Widget Update: Missing numbering cells");
-                    let headers = cells_children
-                        .next()
-                        .expect("Widget Update: Missing header cells");
+                        return event::Status::Captured;
+                    }
+                }
 
-                    let scroll_bounds = {
-                        let diff = padding.vertical()
-                            + pagination.bounds().height.max(goto.bounds().height)
-                            + if table.multiple_pages() { spacing } else { 0.0 }
-                            + status.bounds().height
-                            + spacing
-                            + headers.bounds().height;
+                if matches!(
+                    key,
+                    keyboard::Key::Named(
+                        keyboard::key::Named::Delete | keyboard::key::Named::Backspace
+                    )
+                ) && self.selection.is_some()
+                    && !table.read_only
+                {
+                    self.clear_selected(table, shell);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
 
-                        let height = bounds.height - diff;
-                        let width = bounds.width - padding.horizontal() - numbering.bounds().width;
+                if key.as_ref() == keyboard::Key::Character("v")
+                    && modifiers.command()
+                    && !table.read_only
+                {
+                    let Some((rows, columns)) = self
+                        .selection
+                        .as_ref()
+                        .and_then(|selection| selection.bounds())
+                    else {
+                        return event::Status::Ignored;
+                    };
 
-                        Size::new(width, height)
+                    let Some(contents) = clipboard.read(advanced::clipboard::Kind::Standard) else {
+                        return event::Status::Captured;
                     };
-                    return self.update_cells(
-                        table,
-                        renderer,
-                        event,
-                        cells,
-                        cursor,
-                        shell,
-                        scroll_bounds,
-                    );
-                }
 
-                if cursor.is_over(pagination.bounds()) && table.multiple_pages() {
-                    self.reset();
-                    return self.update_pagination(table, event, pagination, cursor, shell);
-                }
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let lines = utils::tab_separated(&contents);
+                        let mut rejected = 0;
+
+                        for (row, values) in (*rows.start()..table.rows).zip(lines) {
+                            for (column, value) in (*columns.start()..table.cols).zip(values) {
+                                // Uneditable columns are skipped, keeping later values in place
+                                if !table.is_editable(false, column) {
+                                    continue;
+                                }
 
-                if cursor.is_over(goto.bounds()) && table.multiple_pages() {
-                    return self.update_goto(table, renderer, event, goto, cursor, shell);
-                }
+                                // Invalid values are blocked as typed edits are
+                                let is_valid = table
+                                    .raw
+                                    .column_kind(column)
+                                    .is_none_or(|kind| table.validates(&kind, column, value))
+                                    && table.edit_error(value, column).is_none();
 
-                match self.editing.take() {
-                    Some(Editing::Cell {
-                        index,
-                        value,
-                        is_header,
-                        ..
-                    }) => {
-                        if is_header {
-                            if let Some(on_action) = table.on_action.as_ref() {
-                                let action = Action::header_submit(value, index);
-                                let msg = on_action(action);
-                                shell.publish(msg);
-                            }
-                        } else {
-                            let (row, column) =
-                                (index % table.page_limit, index / table.page_limit);
+                                if !is_valid {
+                                    rejected += 1;
+                                    continue;
+                                }
 
-                            if let Some(on_action) = table.on_action.as_ref() {
+                                let value = table.submitted(value.to_owned(), column);
                                 let action = Action::cell_submit(value, column, row);
                                 let msg = on_action(action);
                                 shell.publish(msg);
                             }
                         }
 
-                        self.reset();
-                        shell.invalidate_layout();
-                        return event::Status::Ignored;
-                    }
-                    _ => {
-                        self.reset();
-                        return event::Status::Ignored;
+                        match rejected {
+                            0 => {}
+                            1 => self.notify("1 invalid value not pasted".to_owned(), shell),
+                            len => self.notify(format!("{len} invalid values not pasted"), shell),
+                        }
                     }
+
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
                 }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.is_text_dragging = false;
-                self.reset_resizing();
 
-                if let Some(motion) = self.motion.take() {
-                    if let Some(selection) = self.selection.as_mut() {
-                        selection.update(motion)
-                    }
+                let is_horizontal = matches!(
+                    key.as_ref(),
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowRight
+                    )
+                );
+                let is_vertical = matches!(
+                    key.as_ref(),
+                    keyboard::Key::Named(
+                        keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown
+                    )
+                );
+
+                // Columns are resized from the headers, rows from the numbering
+                if modifiers.alt() && (is_horizontal || is_vertical) {
+                    let (page_start, page_end) = self.page_rows(table).into_inner();
+                    let step = match key.as_ref() {
+                        keyboard::Key::Named(
+                            keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowDown,
+                        ) => table.resize_step,
+                        _ => -table.resize_step,
+                    };
+                    let padding = table.cell_padding;
+
+                    let resizes = match self.selection.as_ref() {
+                        Some(selection) if is_horizontal => selection
+                            .full_columns(&(page_start..=page_end))
+                            .into_iter()
+                            .flatten()
+                            .filter(|column| !table.is_hidden(*column))
+                            .map(|column| {
+                                let resize =
+                                    Resizing::offset(ResizeDirection::Horizontal, 0, column + 1);
+                                let width = self.min_widths[column + 1];
+                                let target = (width + step)
+                                    .min(table.max_column_width.max(width))
+                                    .max(padding.horizontal());
+
+                                (resize, Point::new(target - width, 0.0))
+                            })
+                            .collect::<Vec<_>>(),
+                        Some(selection) => selection
+                            .full_rows(table.cols.saturating_sub(1))
+                            .into_iter()
+                            .flatten()
+                            .filter(|row| (page_start..=page_end).contains(row))
+                            .map(|row| {
+                                let row = row - page_start + 1;
+                                let resize = Resizing::offset(ResizeDirection::Vertical, row, 0);
+                                let height = self.min_heights[row];
+                                let target = (height + step).max(padding.vertical());
+
+                                (resize, Point::new(0.0, target - height))
+                            })
+                            .collect(),
+                        None => vec![],
+                    };
 
-                    if let Some(on_action) = table.on_action.as_ref() {
-                        self.swap_dimensions(motion, table.page_limit);
-                        let msg = on_action(Action::MoveSelection(motion));
-                        shell.publish(msg);
+                    if !resizes.is_empty() {
+                        for (mut resize, position) in resizes {
+                            self.drag_resize(table, &mut resize, position, shell);
+                        }
 
+                        shell.invalidate_layout();
                         return event::Status::Captured;
                     }
                 }
-            }
-            Event::Mouse(mouse::Event::CursorMoved { .. })
-            | Event::Touch(touch::Event::FingerMoved { .. })
-                if self.is_text_dragging =>
-            {
-                match self.editing {
-                    Some(Editing::Goto(_)) => {
-                        return self.update_goto(table, renderer, event, goto, cursor, shell);
-                    }
-                    Some(Editing::Cell { .. }) => {
-                        let mut cells_children = cells.children();
-                        let numbering = cells_children
-                            .next()
-                            .expect("Widget Update: Missing numbering cells");
-                        let headers = cells_children
-                            .next()
-                            .expect("Widget Update: Missing header cells");
-
-                        let scroll_bounds = {
-                            let diff = padding.vertical()
-                                + pagination.bounds().height.max(goto.bounds().height)
-                                + if table.multiple_pages() { spacing } else { 0.0 }
-                                + status.bounds().height
-                                + spacing
-                                + headers.bounds().height;
-
-                            let height = bounds.height - diff;
-                            let width =
-                                bounds.width - padding.horizontal() - numbering.bounds().width;
-
-                            Size::new(width, height)
-                        };
-                        return self.update_cells(
-                            table,
-                            renderer,
-                            event,
-                            cells,
-                            cursor,
-                            shell,
-                            scroll_bounds,
-                        );
+
+                let is_insert = matches!(
+                    key.as_ref(),
+                    keyboard::Key::Character("+") | keyboard::Key::Character("=")
+                );
+                let is_remove = key.as_ref() == keyboard::Key::Character("-");
+
+                if (is_insert || is_remove) && modifiers.command() && !table.read_only {
+                    let (page_start, page_end) = self.page_rows(table).into_inner();
+
+                    // Rows are selected from the numbering, columns from the headers
+                    let Some((axis, range)) = self.selection.as_ref().and_then(|selection| {
+                        selection
+                            .full_rows(table.cols.saturating_sub(1))
+                            .map(|rows| (Axis::Row, rows))
+                            .or_else(|| {
+                                selection
+                                    .full_columns(&(page_start..=page_end))
+                                    .map(|columns| (Axis::Column, columns))
+                            })
+                    }) else {
+                        return event::Status::Ignored;
+                    };
+
+                    let action = if is_insert {
+                        Action::Insert {
+                            axis,
+                            index: *range.start(),
+                        }
+                    } else {
+                        self.reset_selection();
+                        self.motion = None;
+                        Action::Remove { axis, range }
+                    };
+
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        shell.publish(on_action(action));
                     }
-                    None => {}
+
+                    return event::Status::Captured;
                 }
-            }
-            Event::Mouse(mouse::Event::CursorMoved { .. })
-            | Event::Touch(touch::Event::FingerMoved { .. })
-                if self.motion.is_some() =>
-            {
-                let mut cells_children = cells.children();
-                let numbering = cells_children
-                    .next()
-                    .expect("Widget Update: Missing numbering cells");
-                let headers = cells_children
-                    .next()
-                    .expect("Widget Update: Missing header cells");
 
-                let scroll_bounds = {
-                    let diff = padding.vertical()
-                        + pagination.bounds().height.max(goto.bounds().height)
-                        + if table.multiple_pages() { spacing } else { 0.0 }
-                        + status.bounds().height
-                        + spacing
-                        + headers.bounds().height;
+                if key.as_ref() == keyboard::Key::Character("g")
+                    && modifiers.command()
+                    && table.multiple_pages()
+                {
+                    let input = goto
+                        .children()
+                        .nth(1)
+                        .expect("Widget Update: Missing Goto Input");
 
-                    let height = bounds.height - diff;
-                    let width = bounds.width - padding.horizontal() - numbering.bounds().width;
+                    self.cursor.select_all(&self.goto_input.1);
+                    self.editing = Some(Editing::Goto(input.bounds().shrink(self.pages_padding)));
+                    self.motion = None;
+                    self.focus();
+                    return event::Status::Captured;
+                }
 
-                    Size::new(width, height)
-                };
-                return self.update_cells(
-                    table,
-                    renderer,
-                    event,
-                    cells,
-                    cursor,
-                    shell,
-                    scroll_bounds,
-                );
-            }
-            Event::Mouse(mouse::Event::CursorMoved { .. })
-            | Event::Touch(touch::Event::FingerMoved { .. })
-                if self.resizing.is_some() =>
-            {
-                let mut cells_children = cells.children();
-                let numbering = cells_children
-                    .next()
-                    .expect("Widget Update: Missing numbering cells");
-                let headers = cells_children
-                    .next()
-                    .expect("Widget Update: Missing header cells");
+                if key.as_ref() == keyboard::Key::Named(keyboard::key::Named::F3)
+                    && !table.match_query.is_empty()
+                {
+                    let viewport = self.cells_viewport(bounds, padding, cells).size();
 
-                let scroll_bounds = {
-                    let diff = padding.vertical()
-                        + pagination.bounds().height.max(goto.bounds().height)
-                        + if table.multiple_pages() { spacing } else { 0.0 }
-                        + status.bounds().height
-                        + spacing
-                        + headers.bounds().height;
+                    if self.select_match(table, !modifiers.shift(), viewport, shell) {
+                        shell.invalidate_layout();
+                    }
 
-                    let height = bounds.height - diff;
-                    let width = bounds.width - padding.horizontal() - numbering.bounds().width;
+                    return event::Status::Captured;
+                }
 
-                    Size::new(width, height)
-                };
-                return self.update_cells(
-                    table,
-                    renderer,
-                    event,
-                    cells,
-                    cursor,
-                    shell,
-                    scroll_bounds,
-                );
-            }
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
-                let delta = match *delta {
-                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
-                    // Intentionally multiplying by scroll mult twice. Result
-                    // is smoother on windows
-                    mouse::ScrollDelta::Lines { x, y } => Vector::new(x, y) * Self::SCROLL_MULT,
+                let page = match key.as_ref() {
+                    keyboard::Key::Named(keyboard::key::Named::PageDown) => Some(self.page + 1),
+                    keyboard::Key::Named(keyboard::key::Named::PageUp) => {
+                        Some(self.page.saturating_sub(1))
+                    }
+                    // With a selection these move it within the page instead
+                    keyboard::Key::Named(keyboard::key::Named::Home)
+                        if modifiers.command() && self.selection.is_none() =>
+                    {
+                        Some(0)
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::End)
+                        if modifiers.command() && self.selection.is_none() =>
+                    {
+                        Some(table.pages_end())
+                    }
+                    _ => None,
                 };
 
-                let mut cells_children = cells.children();
-                let numbering = cells_children
-                    .next()
-                    .expect("Widget Update: Missing numbering cells");
-                let headers = cells_children
-                    .next()
-                    .expect("Widget Update: Missing header cells");
+                if let Some(page) = page {
+                    self.change_page(table, page, shell);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
 
-                let scroll_bounds = {
-                    let diff = padding.vertical()
-                        + pagination.bounds().height.max(goto.bounds().height)
-                        + if table.multiple_pages() { spacing } else { 0.0 }
-                        + status.bounds().height
-                        + spacing
-                        + headers.bounds().height;
+                let is_space = matches!(key, keyboard::Key::Named(keyboard::key::Named::Space));
 
-                    let height = bounds.height - diff;
-                    let width = bounds.width - padding.horizontal() - numbering.bounds().width;
+                let toggled = self
+                    .selection
+                    .as_ref()
+                    .and_then(|selection| selection.single_cell())
+                    .filter(|(row, column)| {
+                        is_space
+                            && !modifiers.command()
+                            && *row < table.rows
+                            && table.is_toggleable(*column)
+                    });
 
-                    Size::new(width, height)
-                };
+                if let Some((row, column)) = toggled {
+                    self.toggle_cell(table, row, column, shell);
+                    return event::Status::Captured;
+                }
 
-                self.scroll_cells(scroll_bounds, delta);
-                shell.invalidate_layout();
-                return event::Status::Captured;
-            }
+                let typed = text
+                    .as_ref()
+                    .and_then(|text| text.chars().next())
+                    .filter(|c| !c.is_control() && !modifiers.command());
 
-            Event::Keyboard(keyboard::Event::KeyPressed {
-                key,
-                modifiers,
-                text,
-                ..
-            }) if self.editing.is_none() && cursor.is_over(layout.bounds()) => {
-                // todo!() Change cursor bounds check to widget focus check
-                if let Some(callback) = table.on_keypress.as_ref() {
-                    let msg = callback(KeyPress {
-                        key: key.clone(),
-                        modifiers: *modifiers,
-                        text: text.as_ref().map(|text| text.to_string()),
+                if let (Some(c), Some((row, column))) = (
+                    typed,
+                    self.selection
+                        .as_ref()
+                        .and_then(|selection| selection.single_cell()),
+                ) {
+                    let page_rows = self.page_rows(table);
+                    let is_on_page = page_rows.contains(&row);
+
+                    let is_accepted = table.raw.column_kind(column).is_some_and(|kind| {
+                        table.accepts(&kind, column, c)
+                            && table.validates(&kind, column, &c.to_string())
                     });
 
-                    if let Some(msg) = msg {
-                        shell.publish(msg);
+                    if !is_on_page
+                        || !is_accepted
+                        || row >= table.rows
+                        || !table.is_editable(false, column)
+                    {
                         return event::Status::Ignored;
                     }
+
+                    let value = c.to_string();
+                    self.cursor.move_to_end(&value);
+                    self.motion = None;
+                    self.editing = Some(Editing::Cell {
+                        index: (column * table.page_limit) + (row - page_rows.start()),
+                        value: value.clone(),
+                        is_header: false,
+                        history: History::default(),
+                    });
+
+                    self.focus();
+
+                    if let Some(on_action) = table.on_action.as_ref() {
+                        let action = Action::cell_input(value, column, row);
+                        let msg = on_action(action);
+                        shell.publish(msg);
+                    }
+
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                let is_tab = matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab));
+
+                // Empty tables have no cells to move through
+                if table.rows == 0 || table.cols == 0 {
+                    return event::Status::Ignored;
                 }
 
+                let (page_start, page_end) = self.page_rows(table).into_inner();
+
                 let Some(selection) = self.selection.as_mut() else {
                     return event::Status::Ignored;
                 };
 
-                match key {
-                    keyboard::Key::Named(keyboard::key::Named::ArrowRight)
-                        if self.keyboard_modifiers.shift() =>
-                    {
-                        selection.grow(
-                            0,
-                            table.rows.saturating_sub(1),
-                            1,
-                            table.cols.saturating_sub(1),
-                        );
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
-                        selection.move_right(table.cols.saturating_sub(1))
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::Enter)
-                        if self.keyboard_modifiers.shift() =>
-                    {
-                        selection.move_right(table.cols.saturating_sub(1))
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft)
-                        if self.keyboard_modifiers.shift() =>
-                    {
-                        selection.shrink(0, 1)
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => selection.move_left(),
-                    keyboard::Key::Named(keyboard::key::Named::ArrowDown)
-                        if self.keyboard_modifiers.shift() =>
-                    {
-                        selection.grow(
-                            1,
-                            table.rows.saturating_sub(1),
-                            0,
-                            table.cols.saturating_sub(1),
-                        );
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowDown)
-                    | keyboard::Key::Named(keyboard::key::Named::Enter) => {
-                        selection.move_down(table.rows.saturating_sub(1))
-                    }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowUp)
-                        if self.keyboard_modifiers.shift() =>
-                    {
-                        selection.shrink(1, 0)
+                let column_end = table.cols.saturating_sub(1);
+
+                let extension = match key {
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => Some((0, 1)),
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => Some((0, -1)),
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown) => Some((1, 0)),
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => Some((-1, 0)),
+                    _ => None,
+                }
+                .filter(|_| self.keyboard_modifiers.shift());
+
+                let extreme = match key {
+                    keyboard::Key::Named(keyboard::key::Named::Home) => Some(false),
+                    keyboard::Key::Named(keyboard::key::Named::End) => Some(true),
+                    _ => None,
+                };
+
+                if let Some((d_row, d_column)) = extension {
+                    let anchor = selection.anchor(self.selection_anchor);
+
+                    selection.extend(anchor, d_row, d_column, page_start..=page_end, column_end);
+                    self.selection_anchor = Some(anchor);
+                } else if let Some(is_end) = extreme {
+                    let anchor = selection.anchor(self.selection_anchor);
+                    let (row, _) = if self.keyboard_modifiers.shift() {
+                        selection.corner(anchor)
+                    } else {
+                        selection.active()
+                    };
+
+                    let (row, column) = match (self.keyboard_modifiers.command(), is_end) {
+                        (true, false) => (page_start, 0),
+                        (true, true) => (page_end, column_end),
+                        (false, false) => (row, 0),
+                        (false, true) => (row, column_end),
+                    };
+
+                    if self.keyboard_modifiers.shift() {
+                        selection.extend_to(anchor, row, column);
+                        self.selection_anchor = Some(anchor);
+                    } else {
+                        selection.move_to(row, column);
                     }
-                    keyboard::Key::Named(keyboard::key::Named::ArrowUp) => selection.move_up(),
-                    _ => return event::Status::Ignored,
+                } else {
+                    let row_end = table.rows.saturating_sub(1);
+
+                    let step: fn(&mut Selection, usize, usize) = match key {
+                        keyboard::Key::Named(keyboard::key::Named::Tab)
+                            if self.keyboard_modifiers.shift() =>
+                        {
+                            |selection, _, column_end| selection.move_previous(column_end)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::Tab) => {
+                            |selection, row_end, column_end| {
+                                selection.move_next(row_end, column_end)
+                            }
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                            |selection, _, column_end| selection.move_right(column_end)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::Enter)
+                            if self.keyboard_modifiers.shift() =>
+                        {
+                            |selection, _, column_end| selection.move_right(column_end)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+                            |selection, _, _| selection.move_left()
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowDown)
+                        | keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                            |selection, row_end, _| selection.move_down(row_end)
+                        }
+                        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                            |selection, _, _| selection.move_up()
+                        }
+                        _ => return event::Status::Ignored,
+                    };
+
+                    // Moves step over the hidden columns, staying put past the last
+                    table.step_shown(selection, |selection| step(selection, row_end, column_end));
                 }
 
                 if let Some(on_action) = table.on_action.as_ref() {
@@ -3538,40 +7935,35 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
                     let msg = on_action(action);
                     shell.publish(msg);
                 }
+
+                let (row, _) = selection.active();
+                if is_tab && row / table.page_limit != self.page {
+                    self.change_page(table, row / table.page_limit, shell);
+                    shell.invalidate_layout();
+                }
+
                 return event::Status::Captured;
             }
             Event::Keyboard(keyboard::Event::KeyPressed { .. }) => match self.editing {
                 Some(Editing::Goto(_)) => {
-                    return self.update_goto(table, renderer, event, goto, cursor, shell)
+                    return self.update_input(
+                        table, renderer, event, goto, cursor, clipboard, shell, false,
+                    )
+                }
+                Some(Editing::Search(_)) => {
+                    return self.update_input(
+                        table, renderer, event, search, cursor, clipboard, shell, true,
+                    )
                 }
                 Some(Editing::Cell { .. }) => {
-                    let mut cells_children = cells.children();
-                    let numbering = cells_children
-                        .next()
-                        .expect("Widget Update: Missing numbering cells");
-                    let headers = cells_children
-                        .next()
-                        .expect("Widget Update: Missing header cells");
-
-                    let scroll_bounds = {
-                        let diff = padding.vertical()
-                            + pagination.bounds().height.max(goto.bounds().height)
-                            + if table.multiple_pages() { spacing } else { 0.0 }
-                            + status.bounds().height
-                            + spacing
-                            + headers.bounds().height;
-
-                        let height = bounds.height - diff;
-                        let width = bounds.width - padding.horizontal() - numbering.bounds().width;
-
-                        Size::new(width, height)
-                    };
+                    let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
                     return self.update_cells(
                         table,
                         renderer,
                         event,
                         cells,
                         cursor,
+                        clipboard,
                         shell,
                         scroll_bounds,
                     );
@@ -3588,14 +7980,72 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
             }
             Event::Window(window::Event::Focused) => {
                 if let Some(focus) = &mut self.is_focused {
+                    // The blink starts over from a visible cursor
+                    let now = Instant::now();
                     focus.is_window_focused = true;
-                    focus.updated_at = Instant::now();
+                    focus.updated_at = now;
+                    focus.now = now;
 
                     shell.request_redraw(window::RedrawRequest::NextFrame);
                 }
             }
             Event::Window(window::Event::RedrawRequested(now)) => {
-                if let Some(focus) = &mut self.is_focused {
+                self.show_tooltip(table, renderer, cursor, *now);
+
+                if let Some(scroll) = self.smooth_scroll.as_mut() {
+                    if scroll.applied != self.scroll_offset {
+                        self.smooth_scroll = None;
+                    } else {
+                        self.scroll_offset = scroll.advance(*now);
+                        shell.invalidate_layout();
+
+                        if scroll.in_progress(*now) {
+                            shell.request_redraw(window::RedrawRequest::NextFrame);
+                        } else {
+                            self.smooth_scroll = None;
+                        }
+                    }
+                }
+
+                let is_dragging = self.selection_drag.is_some()
+                    || (self.is_text_dragging
+                        && matches!(self.editing, Some(Editing::Cell { .. })));
+
+                if is_dragging && self.auto_scroll != Vector::ZERO {
+                    let scroll_bounds = self.cells_viewport(bounds, padding, cells).size();
+
+                    if self.apply_auto_scroll(scroll_bounds) {
+                        shell.invalidate_layout();
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+
+                if self
+                    .notice
+                    .as_ref()
+                    .is_some_and(|(_, at)| *now >= *at + NOTICE_DURATION)
+                {
+                    self.notice = None;
+                    shell.invalidate_layout();
+                }
+
+                // Only a blinking cursor in view needs redrawing
+                let cells_viewport = self.cells_viewport(bounds, padding, cells);
+                let is_blinking = self.cursor_blink
+                    && self.editing_bounds(cells).is_some_and(|edited| {
+                        let is_cell = matches!(
+                            self.editing,
+                            Some(Editing::Cell {
+                                is_header: false,
+                                ..
+                            })
+                        );
+
+                        edited.intersects(viewport)
+                            && (!is_cell || edited.intersects(&cells_viewport))
+                    });
+
+                if let Some(focus) = self.is_focused.as_mut().filter(|_| is_blinking) {
                     if focus.is_window_focused {
                         focus.now = *now;
 
@@ -3658,9 +8108,117 @@ impl<Renderer: text::Renderer + advanced::Renderer> State<Renderer> {
             cursor_position + translation,
             is_row,
             table.cell_padding,
+            self.cell_gap,
             &table.class,
         );
 
         Some(advanced::overlay::Element::new(Box::new(overlay)))
     }
 }
+
+impl<Renderer: text::Renderer + advanced::Renderer> Focusable for State<Renderer> {
+    fn is_focused(&self) -> bool {
+        self.is_focused.is_some()
+    }
+
+    fn focus(&mut self) {
+        let now = Instant::now();
+
+        self.is_focused.get_or_insert(Focus {
+            updated_at: now,
+            now,
+            is_window_focused: true,
+        });
+    }
+
+    /// Unfocusing stops any editing, but keeps the selection.
+    fn unfocus(&mut self) {
+        self.reset_editing();
+        self.is_focused = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::widget::{
+        tree::{self, Tree},
+        Widget,
+    };
+
+    struct Sheet {
+        rows: usize,
+    }
+
+    impl RawTable for Sheet {
+        type ColumnKind = &'static str;
+
+        fn height(&self) -> usize {
+            self.rows
+        }
+
+        fn width(&self) -> usize {
+            2
+        }
+
+        fn column_header(&self, index: usize) -> Option<String> {
+            Some(format!("Column {index}"))
+        }
+
+        fn column_kind(&self, _index: usize) -> Option<Self::ColumnKind> {
+            Some("Text")
+        }
+
+        fn cell(&self, row: usize, column: usize) -> Option<Cow<'_, str>> {
+            Some(Cow::Owned(format!("{row}:{column}")))
+        }
+
+        fn is_empty(&self) -> bool {
+            self.rows == 0
+        }
+
+        fn column_filter(&self, _kind: &Self::ColumnKind, _character: char) -> bool {
+            true
+        }
+
+        fn kind_alignment(&self, _kind: &Self::ColumnKind) -> Horizontal {
+            Horizontal::Left
+        }
+    }
+
+    fn layout(sheet: &Sheet, tree: &mut Tree) {
+        let table = Table::<'_, Sheet, (), iced::Theme, ()>::new(sheet).page_limit(10);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(800.0, 600.0));
+
+        table.layout(tree, &(), &limits);
+    }
+
+    #[test]
+    fn shrinking_data_clamps_the_page() {
+        let mut tree = Tree {
+            tag: tree::Tag::of::<State<()>>(),
+            state: tree::State::new(State::<()>::new()),
+            children: Vec::new(),
+        };
+
+        layout(&Sheet { rows: 100 }, &mut tree);
+
+        let state = tree.state.downcast_mut::<State<()>>();
+        state.page = 4;
+        state.selection = Some(Selection::new(45, 1));
+        state.editing = Some(Editing::Cell {
+            index: 5,
+            value: String::from("edited"),
+            is_header: false,
+            history: History::default(),
+        });
+
+        layout(&Sheet { rows: 10 }, &mut tree);
+
+        let state = tree.state.downcast_ref::<State<()>>();
+        assert_eq!(state.page, 0);
+        assert_eq!(state.goto_input.1, "1");
+        assert!(state.selection.is_none());
+        assert!(state.editing.is_none());
+    }
+}
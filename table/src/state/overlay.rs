@@ -9,7 +9,7 @@ use iced::{
     Padding, Point, Rectangle, Size,
 };
 
-use super::{draw, Catalog, Cell, CELL_GAP};
+use super::{draw, Catalog, Cell, Status};
 
 const SCALING: f32 = 0.75;
 
@@ -23,6 +23,7 @@ where
     cells: Vec<(Rectangle, &'a Cell<Renderer>, usize)>,
     is_row: bool,
     padding: Padding,
+    gap: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -37,6 +38,7 @@ where
         position: Point,
         is_row: bool,
         padding: Padding,
+        gap: f32,
         class: &'a <Theme as Catalog>::Class<'b>,
     ) -> Self {
         let cells = bounds
@@ -48,6 +50,7 @@ where
             position,
             is_row,
             padding,
+            gap,
             class,
         }
     }
@@ -99,9 +102,10 @@ where
         layout: layout::Layout<'_>,
         _cursor: mouse::Cursor,
     ) {
-        let style = theme.style(self.class);
+        // Cells are only moved while the table is focused
+        let style = theme.style(self.class, Status::Focused);
         let alpha = 0.85;
-        let gap = CELL_GAP / 2.0;
+        let gap = self.gap / 2.0;
 
         for (layout, (_, cell, row)) in layout.children().zip(self.cells.iter()) {
             let bounds = layout.bounds();
@@ -1,5 +1,18 @@
 use iced::{Background, Border, Color, Theme};
 
+/// The possible status of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The default [`Table`] status.
+    Active,
+    /// The [`Table`] has keyboard focus.
+    Focused,
+    /// The [`Table`] has no [`Action`] handler, so it ignores all input.
+    ///
+    /// [`Action`]: crate::Action
+    Disabled,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     /// The [`Background`] of the [`Table`].
@@ -38,18 +51,46 @@ pub struct Style {
     pub alternating_backgrounds: (Background, Background),
     /// The two text colors used by alternate rows in the [`Table`].
     pub alternating_text_color: (Color, Color),
+    /// The [`Background`] of the column group headers.
+    pub group_header_background: Background,
+    /// The text [`Color`] of the column group headers.
+    pub group_header_text: Color,
     /// The border [`Background`] of a header when selected.
     pub selected_header_border: Background,
     /// The border [`Background`] of a header.
     pub header_background: Background,
+    /// The [`Background`] of the top-left corner when hovered.
+    pub hovered_corner_background: Background,
     /// The border [`Background`] of a cell when selected.
     pub selected_cell_border: Background,
     /// The [`Background`] of a cell when selected.
     pub selected_cell_background: Background,
+    /// The [`Background`] of the cells and numbering of the row under the cursor.
+    pub hovered_row_background: Background,
+    /// The [`Background`] of the footer row.
+    pub footer_background: Background,
+    /// The text [`Color`] of the footer row.
+    pub footer_text: Color,
+    /// The [`Background`] of the cells matching the highlighted query.
+    pub match_background: Background,
+    /// The text [`Color`] of the placeholder of cells without a value.
+    pub placeholder_text: Color,
+    /// The border [`Background`] of a cell with a validation error.
+    pub error_cell_border: Background,
+    /// The [`Background`] of a cell with a validation error.
+    pub error_cell_background: Background,
+    /// The border [`Color`] of the full text of a truncated cell when hovered.
+    pub overflow_border: Color,
     /// The border [`Background`] of a cell.
     pub cell_border: Background,
+    /// The [`Color`] of the grid lines between the cells.
+    pub grid_line: Color,
     /// The [`Background`] of the status area.
     pub status_background: Background,
+    /// The [`Background`] dimming the cells while loading.
+    pub loading_background: Background,
+    /// The text [`Color`] of the loading label.
+    pub loading_text: Color,
     /// The [`Border`] of the go-to button.
     pub goto_border: Border,
     /// The [`Background`] of the go-to button.
@@ -72,6 +113,29 @@ pub struct Style {
     pub hovered_page_background: Background,
     /// The [`Background`] of the current page.
     pub selected_page_background: Background,
+    /// The text [`Color`] of the cell tooltips.
+    pub tooltip_text: Color,
+    /// The [`Background`] of the cell tooltips.
+    pub tooltip_background: Background,
+    /// The [`Border`] of the cell tooltips.
+    pub tooltip_border: Border,
+    /// The [`Background`] of the scrollbar tracks.
+    pub scrollbar_track: Background,
+    /// The [`Background`] of the scrollbar thumbs.
+    pub scrollbar_thumb: Background,
+    /// The [`Background`] of the scrollbar thumbs when hovered or dragged.
+    pub hovered_scrollbar_thumb: Background,
+}
+
+/// The style overrides of a single [`Table`] cell.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CellStyle {
+    /// The [`Background`] replacing the alternating row background.
+    pub background: Option<Background>,
+    /// The text [`Color`] replacing the alternating row text color.
+    pub text_color: Option<Color>,
+    /// The [`Border`] drawn around the cell.
+    pub border: Option<Border>,
 }
 
 /// The theme catalog of a [`Table`].
@@ -82,12 +146,12 @@ pub trait Catalog {
     /// The default class produced by the [`Catalog`].
     fn default<'a>() -> Self::Class<'a>;
 
-    /// The [`Style`] of a class.
-    fn style(&self, class: &Self::Class<'_>) -> Style;
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
 }
 
 /// A styling function for a [`Table`].
-pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
@@ -96,13 +160,13 @@ impl Catalog for Theme {
         Box::new(default)
     }
 
-    fn style(&self, class: &Self::Class<'_>) -> Style {
-        class(self)
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
     }
 }
 
 /// The default styling for aa [`iced::Theme`].
-pub fn default(theme: &Theme) -> Style {
+pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
     let background = palette.background.weak;
     let status_background = palette.secondary.weak;
@@ -121,16 +185,35 @@ pub fn default(theme: &Theme) -> Style {
     let cursor = palette.primary.strong;
     let rounded = Border::default().rounded(3.0);
 
+    let border = match status {
+        Status::Focused => Border::default()
+            .width(1.0)
+            .color(palette.primary.strong.color),
+        Status::Active | Status::Disabled => Border::default(),
+    };
+    let text = |color: Color| match status {
+        Status::Disabled => color.scale_alpha(0.5),
+        Status::Active | Status::Focused => color,
+    };
+
     Style {
         background: Some(Background::Color(background.color)),
-        border: Border::default(),
+        border,
 
-        status_text: status_background.text,
+        status_text: text(status_background.text),
         status_background: Background::Color(status_background.color.scale_alpha(0.5)),
 
+        loading_background: Background::Color(background.color.scale_alpha(0.75)),
+        loading_text: text(background.text),
+
         header_background: Background::Color(header_background.color),
-        header_text: header_background.text,
+        hovered_corner_background: Background::Color(palette.secondary.strong.color),
+        header_text: text(header_background.text),
+        footer_background: Background::Color(header_background.color),
+        footer_text: header_background.text,
         header_type: header_background.text,
+        group_header_background: Background::Color(palette.secondary.strong.color),
+        group_header_text: text(palette.secondary.strong.text),
         selected_header_border: Background::Color(palette.primary.strong.color),
 
         goto_background: Background::Color(goto_background.color),
@@ -156,13 +239,28 @@ pub fn default(theme: &Theme) -> Style {
         selected_page_text: selected_page.text,
         page_border: rounded,
 
+        tooltip_text: palette.background.strong.text,
+        tooltip_background: Background::Color(palette.background.strong.color),
+        tooltip_border: rounded,
+
+        scrollbar_track: Background::Color(palette.background.strong.color.scale_alpha(0.5)),
+        scrollbar_thumb: Background::Color(palette.secondary.strong.color),
+        hovered_scrollbar_thumb: Background::Color(palette.primary.base.color),
+
         cursor_color: cursor.color,
         cursor_selection: cursor.color.scale_alpha(0.5),
 
-        alternating_text_color: (alt1.text, alt2.text),
+        alternating_text_color: (text(alt1.text), text(alt2.text)),
         alternating_backgrounds: (Background::Color(alt1.color), Background::Color(alt2.color)),
         cell_border: Background::Color(palette.primary.weak.color),
+        grid_line: palette.primary.weak.color,
         selected_cell_border: Background::Color(palette.primary.strong.color),
         selected_cell_background: Background::Color(palette.primary.weak.color.scale_alpha(0.75)),
+        hovered_row_background: Background::Color(palette.primary.base.color.scale_alpha(0.15)),
+        match_background: Background::Color(palette.success.weak.color.scale_alpha(0.6)),
+        placeholder_text: palette.background.base.text.scale_alpha(0.5),
+        error_cell_border: Background::Color(palette.danger.strong.color),
+        error_cell_background: Background::Color(palette.danger.weak.color.scale_alpha(0.5)),
+        overflow_border: palette.primary.strong.color,
     }
 }
@@ -1,9 +1,13 @@
-use iced::{alignment::Horizontal, keyboard, mouse, time::Instant, Point, Rectangle, Size, Vector};
-use std::collections::HashSet;
+use iced::{
+    alignment::Horizontal, keyboard, mouse, time::Instant, touch, Point, Rectangle, Size, Vector,
+};
+use lilt::{Animated, Easing};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashSet};
 
 #[allow(unused_imports)]
 use super::Table;
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
@@ -26,7 +30,7 @@ pub struct Cursor {
 
 impl Cursor {
     pub fn state(&self, value: &str) -> State {
-        let len = value.len();
+        let len = char_len(value);
         match self.state {
             State::Index(idx) => State::Index(idx.min(len)),
             State::Selection { start, end } => {
@@ -42,6 +46,26 @@ impl Cursor {
         }
     }
 
+    /// Returns the selected text of `value`, if any.
+    pub fn selected<'b>(&self, value: &'b str) -> Option<&'b str> {
+        let (start, end) = self.selection(value)?;
+
+        Some(&value[char_range(value, start, end)])
+    }
+
+    /// Returns `value` as it would be with `text` typed at the cursor,
+    /// replacing the selection.
+    pub fn replaced(&self, value: &str, text: &str) -> String {
+        let (start, end) = self.selection(value).unwrap_or_else(|| {
+            let index = self.end(value);
+            (index, index)
+        });
+        let mut value = value.to_owned();
+        value.replace_range(char_range(&value, start, end), text);
+
+        value
+    }
+
     pub fn selection(&self, value: &str) -> Option<(usize, usize)> {
         match self.state(value) {
             State::Selection { start, end } => Some((start.min(end), start.max(end))),
@@ -54,7 +78,7 @@ impl Cursor {
     }
 
     pub fn move_to_end(&mut self, value: &str) {
-        self.state = State::Index(value.len());
+        self.state = State::Index(char_len(value));
     }
 
     pub fn move_left(&mut self, value: &str) {
@@ -71,7 +95,7 @@ impl Cursor {
 
     pub fn move_right_by_amount(&mut self, value: &str, amount: usize) {
         match self.state(value) {
-            State::Index(idx) => self.move_to(idx.saturating_add(amount).min(value.len())),
+            State::Index(idx) => self.move_to(idx.saturating_add(amount).min(char_len(value))),
             State::Selection { start, end } => self.move_to(end.max(start)),
         }
     }
@@ -96,13 +120,13 @@ impl Cursor {
 
     pub fn select_to_end(&mut self, value: &str) {
         match self.state(value) {
-            State::Index(index) => self.select_range(index, value.len()),
-            State::Selection { start, .. } => self.select_range(start, value.len()),
+            State::Index(index) => self.select_range(index, char_len(value)),
+            State::Selection { start, .. } => self.select_range(start, char_len(value)),
         }
     }
 
     pub fn select_all(&mut self, value: &str) {
-        self.select_range(0, value.len())
+        self.select_range(0, char_len(value))
     }
 
     pub fn select_left(&mut self, value: &str) {
@@ -119,23 +143,51 @@ impl Cursor {
 
     pub fn select_right(&mut self, value: &str) {
         match self.state(value) {
-            State::Index(index) if index < value.len() => {
+            State::Index(index) if index < char_len(value) => {
                 self.select_range(index, index + 1);
             }
-            State::Selection { start, end } if end < value.len() => {
+            State::Selection { start, end } if end < char_len(value) => {
                 self.select_range(start, end + 1);
             }
             _ => {}
         }
     }
 
+    pub fn move_word_left(&mut self, value: &str) {
+        match self.state(value) {
+            State::Index(index) => self.move_to(previous_word(value, index)),
+            State::Selection { start, end } => self.move_to(previous_word(value, start.min(end))),
+        }
+    }
+
+    pub fn move_word_right(&mut self, value: &str) {
+        match self.state(value) {
+            State::Index(index) => self.move_to(next_word(value, index)),
+            State::Selection { start, end } => self.move_to(next_word(value, start.max(end))),
+        }
+    }
+
+    pub fn select_word_left(&mut self, value: &str) {
+        match self.state(value) {
+            State::Index(index) => self.select_range(index, previous_word(value, index)),
+            State::Selection { start, end } => self.select_range(previous_word(value, start), end),
+        }
+    }
+
+    pub fn select_word_right(&mut self, value: &str) {
+        match self.state(value) {
+            State::Index(index) => self.select_range(index, next_word(value, index)),
+            State::Selection { start, end } => self.select_range(start, next_word(value, end)),
+        }
+    }
+
     pub fn start(&self, value: &str) -> usize {
         let start = match self.state {
             State::Index(idx) => idx,
             State::Selection { start, .. } => start,
         };
 
-        start.min(value.len())
+        start.min(char_len(value))
     }
 
     pub fn end(&self, value: &str) -> usize {
@@ -144,7 +196,7 @@ impl Cursor {
             State::Selection { end, .. } => end,
         };
 
-        end.min(value.len())
+        end.min(char_len(value))
     }
 
     pub fn _left(&self, value: &str) -> usize {
@@ -162,48 +214,287 @@ impl Cursor {
     }
 }
 
+/// Returns the number of characters of `value`, which [`Cursor`] positions
+/// count in.
+pub(super) fn char_len(value: &str) -> usize {
+    value.chars().count()
+}
+
+/// Returns the byte offset of the character at `index` in `value`.
+fn byte_offset(value: &str, index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(index)
+        .map_or(value.len(), |(offset, _)| offset)
+}
+
+/// Returns the byte range of the characters from `start` to `end` in `value`.
+fn char_range(value: &str, start: usize, end: usize) -> Range<usize> {
+    byte_offset(value, start)..byte_offset(value, end)
+}
+
+/// Returns `true` if `character` belongs in a word, as word jumps and
+/// double clicks count them.
+pub(super) fn is_word(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Returns the start of the word before `index` in `value`, skipping the
+/// punctuation and whitespace before it.
+fn previous_word(value: &str, index: usize) -> usize {
+    let chars = value.chars().take(index).collect::<Vec<char>>();
+    let mut start = chars.len();
+
+    while start > 0 && !is_word(chars[start - 1]) {
+        start -= 1;
+    }
+
+    while start > 0 && is_word(chars[start - 1]) {
+        start -= 1;
+    }
+
+    start
+}
+
+/// Returns the end of the word after `index` in `value`, skipping the
+/// punctuation and whitespace before it.
+fn next_word(value: &str, index: usize) -> usize {
+    let chars = value.chars().collect::<Vec<char>>();
+    let mut end = index.min(chars.len());
+
+    while end < chars.len() && !is_word(chars[end]) {
+        end += 1;
+    }
+
+    while end < chars.len() && is_word(chars[end]) {
+        end += 1;
+    }
+
+    end
+}
+
+/// The kind of the last edit recorded by a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Insert,
+    Backspace,
+    Delete,
+}
+
+/// The undo and redo stacks of a cell [`Editor`].
+///
+/// Consecutive edits of the same kind are undone together, up to a replaced
+/// selection or an inserted whitespace.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<(String, Cursor)>,
+    redo: Vec<(String, Cursor)>,
+    last: Option<Edit>,
+}
+
+impl History {
+    fn record(&mut self, edit: Edit, value: &str, cursor: Cursor, is_boundary: bool) {
+        if is_boundary || self.last != Some(edit) {
+            self.undo.push((value.to_owned(), cursor));
+        }
+
+        self.redo.clear();
+        self.last = Some(edit);
+    }
+
+    fn undo(&mut self, value: &mut String, cursor: &mut Cursor) -> bool {
+        let Some((previous, position)) = self.undo.pop() else {
+            return false;
+        };
+
+        self.redo
+            .push((std::mem::replace(value, previous), *cursor));
+        *cursor = position;
+        self.last = None;
+
+        true
+    }
+
+    fn redo(&mut self, value: &mut String, cursor: &mut Cursor) -> bool {
+        let Some((next, position)) = self.redo.pop() else {
+            return false;
+        };
+
+        self.undo.push((std::mem::replace(value, next), *cursor));
+        *cursor = position;
+        self.last = None;
+
+        true
+    }
+}
+
 pub struct Editor<'a> {
     value: &'a mut String,
     cursor: &'a mut Cursor,
+    history: Option<&'a mut History>,
 }
 
 impl<'a> Editor<'a> {
     pub fn new(value: &'a mut String, cursor: &'a mut Cursor) -> Self {
-        Self { value, cursor }
+        Self {
+            value,
+            cursor,
+            history: None,
+        }
+    }
+
+    /// Creates an [`Editor`] recording its edits in `history`.
+    pub fn with_history(
+        value: &'a mut String,
+        cursor: &'a mut Cursor,
+        history: &'a mut History,
+    ) -> Self {
+        Self {
+            value,
+            cursor,
+            history: Some(history),
+        }
     }
 
     pub fn _contents(&self) -> String {
         self.value.to_string()
     }
 
-    pub fn insert(&mut self, character: char) {
-        if let Some((left, right)) = self.cursor.selection(self.value) {
-            self.cursor.move_left(self.value);
-            self.value.replace_range(left..right, "");
+    fn record(&mut self, edit: Edit, is_boundary: bool) {
+        let is_boundary = is_boundary || self.cursor.selection(self.value).is_some();
+
+        if let Some(history) = self.history.as_mut() {
+            history.record(edit, self.value, *self.cursor, is_boundary);
         }
+    }
+
+    /// Restores the value before the last group of edits. Returns whether
+    /// there was one.
+    pub fn undo(&mut self) -> bool {
+        self.history
+            .as_mut()
+            .is_some_and(|history| history.undo(self.value, self.cursor))
+    }
+
+    /// Restores the value before the last undo. Returns whether there was one.
+    pub fn redo(&mut self) -> bool {
+        self.history
+            .as_mut()
+            .is_some_and(|history| history.redo(self.value, self.cursor))
+    }
 
-        self.value.insert(self.cursor.end(self.value), character);
+    pub fn insert(&mut self, character: char) {
+        self.record(Edit::Insert, character.is_whitespace());
+
+        self.remove_selection();
+
+        let offset = byte_offset(self.value, self.cursor.end(self.value));
+        self.value.insert(offset, character);
         self.cursor.move_right(self.value)
     }
 
+    /// Inserts the characters of `text` accepted by `filter` in place of the
+    /// selection, as a single edit, if `validate` accepts the resulting value.
+    /// Returns whether any were inserted.
+    pub fn insert_str(
+        &mut self,
+        text: &str,
+        filter: impl Fn(char) -> bool,
+        validate: impl Fn(&str) -> bool,
+    ) -> bool {
+        let text = text.chars().filter(|c| filter(*c)).collect::<String>();
+
+        if text.is_empty() || !validate(&self.cursor.replaced(self.value, &text)) {
+            return false;
+        }
+
+        self.record(Edit::Insert, true);
+
+        self.remove_selection();
+
+        let offset = byte_offset(self.value, self.cursor.end(self.value));
+        self.value.insert_str(offset, &text);
+        self.cursor
+            .move_right_by_amount(self.value, char_len(&text));
+
+        true
+    }
+
+    /// Removes and returns the selected text, if any.
+    pub fn cut(&mut self) -> Option<String> {
+        let selected = self.cursor.selected(self.value)?.to_owned();
+        self.backspace();
+
+        Some(selected)
+    }
+
+    /// Removes the selected text, moving the cursor to where it started.
+    fn remove_selection(&mut self) {
+        if let Some((start, end)) = self.cursor.selection(self.value) {
+            self.cursor.move_left(self.value);
+            self.value
+                .replace_range(char_range(self.value, start, end), "");
+        }
+    }
+
     pub fn backspace(&mut self) {
+        if self.cursor.selection(self.value).is_some() || self.cursor.start(self.value) > 0 {
+            self.record(Edit::Backspace, false);
+        }
+
         match self.cursor.selection(self.value) {
-            Some((start, end)) => {
-                self.cursor.move_left(self.value);
-                self.value.replace_range(start..end, "");
-            }
+            Some(_) => self.remove_selection(),
             None => {
                 let start = self.cursor.start(self.value);
 
                 if start > 0 {
                     self.cursor.move_left(self.value);
-                    self.value.remove(start - 1);
+                    self.value.remove(byte_offset(self.value, start - 1));
                 }
             }
         }
     }
 
+    /// Removes the selection, or up to the start of the previous word.
+    pub fn backspace_word(&mut self) {
+        if self.cursor.selection(self.value).is_some() {
+            return self.backspace();
+        }
+
+        let end = self.cursor.start(self.value);
+        let start = previous_word(self.value, end);
+
+        if start < end {
+            self.record(Edit::Backspace, true);
+            self.value
+                .replace_range(char_range(self.value, start, end), "");
+            self.cursor.move_to(start);
+        }
+    }
+
+    /// Removes the selection, or up to the end of the next word.
+    pub fn delete_word(&mut self) {
+        if self.cursor.selection(self.value).is_some() {
+            return self.delete();
+        }
+
+        let start = self.cursor.end(self.value);
+        let end = next_word(self.value, start);
+
+        if start < end {
+            self.record(Edit::Delete, true);
+            self.value
+                .replace_range(char_range(self.value, start, end), "");
+        }
+    }
+
     pub fn delete(&mut self) {
+        if self.cursor.selection(self.value).is_some()
+            || self.cursor.end(self.value) < char_len(self.value)
+        {
+            self.record(Edit::Delete, false);
+        }
+
         match self.cursor.selection(self.value) {
             Some(_) => {
                 self.backspace();
@@ -211,8 +502,8 @@ impl<'a> Editor<'a> {
             None => {
                 let end = self.cursor.end(self.value);
 
-                if end < self.value.len() {
-                    self.value.remove(end);
+                if end < char_len(self.value) {
+                    self.value.remove(byte_offset(self.value, end));
                 }
             }
         }
@@ -222,10 +513,12 @@ impl<'a> Editor<'a> {
 #[derive(Debug, Clone)]
 pub enum Editing {
     Goto(Rectangle),
+    Search(Rectangle),
     Cell {
         index: usize,
         value: String,
         is_header: bool,
+        history: History,
     },
 }
 
@@ -238,6 +531,10 @@ pub struct Focus {
 
 #[derive(Debug, Clone, PartialEq)]
 /// A group of selected cells.
+///
+/// Rows are absolute data rows rather than rows of the current page, so a
+/// selection keeps pointing at the same data across pages and is only drawn
+/// on the pages holding its rows.
 pub enum Selection {
     /// A continuous selection.
     Block {
@@ -266,13 +563,27 @@ impl Selection {
         }
     }
 
-    pub(super) fn column(column: usize, limit: usize) -> Self {
+    pub(super) fn column(column: usize, rows: RangeInclusive<usize>) -> Self {
         Self::Block {
-            rows: 0..=limit,
+            rows,
             columns: column..=column,
         }
     }
 
+    pub(super) fn column_range(
+        columns: RangeInclusive<usize>,
+        rows: RangeInclusive<usize>,
+    ) -> Self {
+        Self::Block { rows, columns }
+    }
+
+    pub(super) fn row_range(rows: RangeInclusive<usize>, column_end: usize) -> Self {
+        Self::Block {
+            rows,
+            columns: 0..=column_end,
+        }
+    }
+
     pub(super) fn block(&mut self, row: usize, column: usize) {
         match self {
             Self::Block { rows, columns } => {
@@ -330,6 +641,83 @@ impl Selection {
         }
     }
 
+    /// Removes the cell at `row` and `column` if it is already selected,
+    /// otherwise adds it to the [`Selection`].
+    pub(super) fn toggle(&mut self, row: usize, column: usize) {
+        if !self.contains(row, column) {
+            self.scattered(row, column);
+            return;
+        }
+
+        if let Self::Block { rows, columns } = self {
+            let cells = rows
+                .clone()
+                .flat_map(|row| columns.clone().map(move |column| (row, column)))
+                .collect();
+
+            *self = Self::Scattered {
+                cells,
+                last: (row, column),
+            }
+        }
+
+        if let Self::Scattered { cells, last } = self {
+            cells.remove(&(row, column));
+
+            // The nearest remaining cell anchors the next extension
+            if *last == (row, column) {
+                *last = cells
+                    .iter()
+                    .copied()
+                    .min_by_key(|cell| (cell.0.abs_diff(row) + cell.1.abs_diff(column), *cell))
+                    .unwrap_or((row, column));
+            }
+        }
+    }
+
+    /// Returns `true` if the [`Selection`] has no cells.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Block { rows, columns } => rows.is_empty() || columns.is_empty(),
+            Self::Scattered { cells, .. } => cells.is_empty(),
+        }
+    }
+
+    /// Returns the number of cells in the [`Selection`].
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Block { rows, columns } => rows.clone().count() * columns.clone().count(),
+            Self::Scattered { cells, .. } => cells.len(),
+        }
+    }
+
+    /// Returns the rows holding selected cells, in ascending order.
+    pub fn rows(&self) -> impl Iterator<Item = usize> {
+        let rows: BTreeSet<usize> = match self {
+            Self::Block { rows, columns } if !columns.is_empty() => rows.clone().collect(),
+            Self::Block { .. } => BTreeSet::new(),
+            Self::Scattered { cells, .. } => cells.iter().map(|(row, _)| *row).collect(),
+        };
+
+        rows.into_iter()
+    }
+
+    /// Returns the columns holding selected cells, in ascending order.
+    pub fn columns(&self) -> impl Iterator<Item = usize> {
+        let columns: BTreeSet<usize> = match self {
+            Self::Block { rows, columns } if !rows.is_empty() => columns.clone().collect(),
+            Self::Block { .. } => BTreeSet::new(),
+            Self::Scattered { cells, .. } => cells.iter().map(|(_, column)| *column).collect(),
+        };
+
+        columns.into_iter()
+    }
+
+    /// Returns `true` if the [`Selection`] is a single cell.
+    pub fn is_single_cell(&self) -> bool {
+        self.single_cell().is_some()
+    }
+
     /// Returns`true` if the [`Selection`] contains the given `row` and
     /// `column`.
     pub fn contains(&self, row: usize, column: usize) -> bool {
@@ -388,6 +776,52 @@ impl Selection {
         }
     }
 
+    /// Returns the rows of the [`Selection`] if it spans every column up to
+    /// `column_end`.
+    pub(super) fn full_rows(&self, column_end: usize) -> Option<RangeInclusive<usize>> {
+        match self {
+            Self::Block { rows, columns } if *columns == (0..=column_end) => Some(rows.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the columns of the [`Selection`] if it spans all of `rows`.
+    pub(super) fn full_columns(
+        &self,
+        rows: &RangeInclusive<usize>,
+    ) -> Option<RangeInclusive<usize>> {
+        match self {
+            Self::Block {
+                rows: selected,
+                columns,
+            } if selected.start() <= rows.start() && selected.end() >= rows.end() => {
+                Some(columns.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the `(row, column)` of the [`Selection`] if it is a single cell.
+    pub fn single_cell(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Block { rows, columns }
+                if rows.start() == rows.end() && columns.start() == columns.end() =>
+            {
+                Some((*rows.start(), *columns.start()))
+            }
+            Self::Scattered { cells, .. } if cells.len() == 1 => cells.iter().next().copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(row, column)` of the cell movements start from.
+    pub(super) fn active(&self) -> (usize, usize) {
+        match self {
+            Self::Block { rows, columns } => (*rows.start(), *columns.start()),
+            Self::Scattered { last, .. } => *last,
+        }
+    }
+
     pub(super) fn move_to(&mut self, row: usize, column: usize) {
         *self = Self::Block {
             rows: row..=row,
@@ -427,6 +861,34 @@ impl Selection {
         }
     }
 
+    /// Moves one column to the right, wrapping to the first column of the
+    /// next row.
+    pub(super) fn move_next(&mut self, row_limit: usize, column_limit: usize) {
+        let (row, column) = self.active();
+
+        if column < column_limit {
+            self.move_right(column_limit)
+        } else if row < row_limit {
+            self.move_to(row + 1, 0)
+        } else {
+            self.move_to(row, column)
+        }
+    }
+
+    /// Moves one column to the left, wrapping to the last column of the
+    /// previous row.
+    pub(super) fn move_previous(&mut self, column_limit: usize) {
+        let (row, column) = self.active();
+
+        if column > 0 {
+            self.move_left()
+        } else if row > 0 {
+            self.move_to(row - 1, column_limit)
+        } else {
+            self.move_to(row, column)
+        }
+    }
+
     pub(super) fn move_down(&mut self, row_limit: usize) {
         match self {
             Self::Block { rows, columns } => {
@@ -459,40 +921,69 @@ impl Selection {
         }
     }
 
-    pub(super) fn grow(
-        &mut self,
-        row_amt: usize,
-        row_limit: usize,
-        column_amt: usize,
-        column_limit: usize,
-    ) {
-        if let Self::Block { rows, columns } = self {
-            let end = (*rows.end() + row_amt).min(row_limit);
-            *rows = *rows.start()..=end;
+    /// Returns the corner of the [`Selection`] held in place while extending
+    /// it, preferring `anchor` when it is one of the block's corners.
+    pub(super) fn anchor(&self, anchor: Option<(usize, usize)>) -> (usize, usize) {
+        match (self, anchor) {
+            (Self::Block { rows, columns }, Some((row, column)))
+                if (row == *rows.start() || row == *rows.end())
+                    && (column == *columns.start() || column == *columns.end()) =>
+            {
+                (row, column)
+            }
+            _ => self.active(),
+        }
+    }
 
-            let end = (*columns.end() + column_amt).min(column_limit);
-            *columns = *columns.start()..=end;
+    /// Returns the corner of the block opposite `anchor`, or `anchor` itself
+    /// for scattered selections.
+    pub(super) fn corner(&self, anchor: (usize, usize)) -> (usize, usize) {
+        match self {
+            Self::Block { rows, columns } => (
+                if anchor.0 == *rows.start() {
+                    *rows.end()
+                } else {
+                    *rows.start()
+                },
+                if anchor.1 == *columns.start() {
+                    *columns.end()
+                } else {
+                    *columns.start()
+                },
+            ),
+            Self::Scattered { .. } => anchor,
         }
     }
 
-    pub(super) fn shrink(&mut self, row_amt: usize, column_amt: usize) {
-        if let Self::Block { rows, columns } = self {
-            let end = *rows.end();
-            if end == 0 {
-                return;
-            }
-            let end = (end - row_amt).max(*rows.start());
+    /// Moves the corner of the block opposite `anchor` by `d_row` rows and
+    /// `d_column` columns, clamped to `row_limits` and `column_end`.
+    ///
+    /// Scattered selections become a block anchored at `anchor`.
+    pub(super) fn extend(
+        &mut self,
+        anchor: (usize, usize),
+        d_row: isize,
+        d_column: isize,
+        row_limits: RangeInclusive<usize>,
+        column_end: usize,
+    ) {
+        let (row, column) = self.corner(anchor);
 
-            *rows = *rows.start()..=end;
+        let row = row
+            .saturating_add_signed(d_row)
+            .clamp(*row_limits.start(), *row_limits.end());
+        let column = column.saturating_add_signed(d_column).min(column_end);
 
-            let end = *columns.end();
-            if end == 0 {
-                return;
-            }
-            let end = (end - column_amt).max(*columns.start());
+        self.extend_to(anchor, row, column);
+    }
 
-            *columns = *columns.start()..=end;
-        }
+    /// Replaces the [`Selection`] with the block spanning `anchor` to `row`
+    /// and `column`.
+    pub(super) fn extend_to(&mut self, anchor: (usize, usize), row: usize, column: usize) {
+        *self = Self::Block {
+            rows: anchor.0.min(row)..=anchor.0.max(row),
+            columns: anchor.1.min(column)..=anchor.1.max(column),
+        };
     }
 
     pub(super) fn motion(&self) -> Option<Motion> {
@@ -552,6 +1043,75 @@ impl Selection {
         }
     }
 
+    /// Returns the smallest row and column ranges containing every cell in the
+    /// [`Selection`].
+    pub fn bounds(&self) -> Option<(RangeInclusive<usize>, RangeInclusive<usize>)> {
+        match self {
+            Self::Block { rows, columns } => Some((rows.clone(), columns.clone())),
+            Self::Scattered { cells, .. } => {
+                let rows = cells.iter().map(|(row, _)| *row);
+                let columns = cells.iter().map(|(_, column)| *column);
+
+                let rows = rows.clone().min()?..=rows.max()?;
+                let columns = columns.clone().min()?..=columns.max()?;
+
+                Some((rows, columns))
+            }
+        }
+    }
+
+    /// Returns the selected cells of `raw` as tab separated rows, using empty
+    /// values for unselected cells within the [`Selection`]'s bounds.
+    ///
+    /// Unlike [`Selection::render`], values are written as they are so they
+    /// paste back unchanged.
+    pub(super) fn copy<T: RawTable>(&self, raw: &T) -> String {
+        self.join(raw, '\t', |value| value)
+    }
+
+    /// Returns the selected cells of `raw` as `delimiter` separated rows,
+    /// using empty values for unselected cells within the [`Selection`]'s
+    /// bounds.
+    ///
+    /// Values holding the delimiter, a quote or a line break are quoted.
+    pub fn render<T: RawTable>(&self, raw: &T, delimiter: char) -> String {
+        self.join(raw, delimiter, |value| quoted(value, delimiter))
+    }
+
+    /// Joins the selected cells of `raw`, each passed through `write`, into
+    /// `delimiter` separated rows.
+    fn join<T: RawTable>(
+        &self,
+        raw: &T,
+        delimiter: char,
+        write: impl Fn(String) -> String,
+    ) -> String {
+        let Some((rows, columns)) = self.bounds().filter(|_| !self.is_empty()) else {
+            return String::new();
+        };
+
+        let end = (*rows.end()).min(raw.height().saturating_sub(1));
+
+        (*rows.start()..=end)
+            .map(|row| {
+                columns
+                    .clone()
+                    .map(|column| {
+                        if self.contains(row, column) {
+                            raw.cell(row, column)
+                                .map(|value| write(value.into_owned()))
+                                .unwrap_or_default()
+                        } else {
+                            String::new()
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(&delimiter.to_string())
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     /// Returns the `(row, column)` indices for each unique cell in the [`Selection`].
     pub fn list(&self) -> HashSet<(usize, usize)> {
         match self {
@@ -573,6 +1133,67 @@ impl Selection {
     }
 }
 
+/// Wraps `value` in quotes, doubling its own, if it holds the `delimiter`, a
+/// quote or a line break.
+fn quoted(value: String, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Splits clipboard `contents` into rows of tab separated values, as written
+/// by [`Selection::copy`].
+pub(super) fn tab_separated(
+    contents: &str,
+) -> impl Iterator<Item = impl Iterator<Item = &str> + '_> + '_ {
+    contents
+        .strip_suffix('\n')
+        .unwrap_or(contents)
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).split('\t'))
+}
+
+/// A row or column of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Column,
+}
+
+/// The part of a [`Table`] a [`CellTarget`] is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    /// A column header. The `row` of its [`CellTarget`] is always `0`.
+    Header,
+    /// A row number. The `column` of its [`CellTarget`] is always `0`.
+    Numbering,
+    /// A data cell.
+    Cell,
+}
+
+/// A header, row number or data cell of a [`Table`]. The `row` is absolute,
+/// counted from the first page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellTarget {
+    pub row: usize,
+    pub column: usize,
+    pub kind: TargetKind,
+}
+
+/// The header or data cell being edited in a [`Table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditState {
+    /// The edited header or data cell.
+    pub target: CellTarget,
+    /// The value being edited.
+    pub value: String,
+    /// The selected characters of the value, empty at the text cursor when
+    /// nothing is selected.
+    pub cursor: Range<usize>,
+}
+
 /// A [`Selection`] movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Motion {
@@ -623,6 +1244,106 @@ impl Motion {
     }
 }
 
+/// How cell text wider than its column is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// The text is cut off at the cell edge.
+    #[default]
+    Clip,
+    /// The text is truncated with "…" and shown in full on hover.
+    Ellipsis,
+    /// The text wraps and the row grows to fit it.
+    Grow,
+}
+
+/// Where clicking an ellipsis of the [`Table`] pagination moves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EllipsisJump {
+    /// The page halfway between the pages on both sides of the ellipsis.
+    #[default]
+    Midpoint,
+    /// The page this many pages from the current one, towards the ellipsis.
+    Step(usize),
+    /// Nowhere, the ellipsis cannot be clicked.
+    None,
+}
+
+/// The order a [`Table`] column is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// From the smallest value to the largest.
+    Ascending,
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+/// The display format of numeric columns in a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NumericFormat {
+    /// The character inserted between groups of thousands.
+    pub thousands_separator: Option<char>,
+    /// The maximum number of decimals shown. Longer values are rounded.
+    pub max_decimals: Option<u8>,
+}
+
+impl NumericFormat {
+    /// Returns the formatted `value`. Values which are not numbers are
+    /// returned as is.
+    pub(super) fn format(&self, value: &str) -> String {
+        let rounded = match self.max_decimals.map(usize::from) {
+            Some(decimals)
+                if value
+                    .split_once('.')
+                    .is_some_and(|(_, fraction)| fraction.len() > decimals) =>
+            {
+                value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|number| number.is_finite())
+                    .map(|number| format!("{number:.decimals$}"))
+            }
+            _ => None,
+        };
+        let value = rounded.as_deref().unwrap_or(value);
+
+        let Some(separator) = self.thousands_separator else {
+            return value.to_owned();
+        };
+
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", value),
+        };
+        let end = unsigned
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(unsigned.len());
+        let (integer, rest) = unsigned.split_at(end);
+
+        let mut formatted = String::with_capacity(value.len() + integer.len() / 3);
+        formatted.push_str(sign);
+
+        for (idx, digit) in integer.chars().enumerate() {
+            if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                formatted.push(separator);
+            }
+
+            formatted.push(digit);
+        }
+
+        formatted.push_str(rest);
+        formatted
+    }
+
+    /// Returns `value` without any thousands separators.
+    pub(super) fn strip(&self, value: String) -> String {
+        match self.thousands_separator {
+            Some(separator) if value.contains(separator) => value.replace(separator, ""),
+            _ => value,
+        }
+    }
+}
+
 /// The direction in which a resize occurs
 #[derive(Debug, Clone, Copy)]
 pub enum ResizeDirection {
@@ -688,6 +1409,17 @@ impl Resizing {
         })
     }
 
+    /// Starts a resize of `row` and/or `column` driven by offsets from the
+    /// origin, such as from the keyboard, rather than by the cursor.
+    pub(super) fn offset(kind: ResizeDirection, row: usize, column: usize) -> Self {
+        Self {
+            kind,
+            cursor: Point::ORIGIN,
+            row,
+            column,
+        }
+    }
+
     /// Returns the new minimum dimensions after a drag
     pub(super) fn drag(&mut self, position: Point, width: f32, height: f32) -> (Size, Vector) {
         let diff = position - self.cursor;
@@ -713,6 +1445,10 @@ impl Resizing {
         }
     }
 
+    pub(super) fn direction(&self) -> ResizeDirection {
+        self.kind
+    }
+
     pub(super) fn interaction(self) -> mouse::Interaction {
         match self.kind {
             ResizeDirection::Vertical => mouse::Interaction::ResizingVertically,
@@ -721,16 +1457,238 @@ impl Resizing {
         }
     }
 
-    pub(super) fn action(&self, size: Size) -> Action {
+    /// Returns the [`Action::Resize`] for the new `size`.
+    ///
+    /// `row_offset` is the absolute index of the first row on the current
+    /// page.
+    pub(super) fn action(&self, size: Size, row_offset: usize) -> Action {
         Action::Resize {
             direction: self.kind,
             column: self.column.saturating_sub(1),
-            row: self.row.saturating_sub(1),
+            row: self.row.saturating_sub(1) + row_offset,
             size,
         }
     }
 }
 
+/// The text a data cell paragraph was last shaped from, letting layouts
+/// which change nothing skip shaping it again.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Shaped {
+    pub(super) value: String,
+    /// The bounds of `value` before it is fitted to its column.
+    pub(super) min_bounds: Size,
+    /// The width the paragraph was truncated to, if it was.
+    pub(super) truncated_to: Option<f32>,
+    /// The bounds the paragraph was wrapped to, if it was.
+    pub(super) wrapped_to: Option<Size>,
+}
+
+/// What the tooltip waiting to show over a [`Table`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TooltipTarget {
+    /// The data cell at an absolute `row` and `column`.
+    Cell { row: usize, column: usize },
+    /// The kind of a column, under its header.
+    Kind(usize),
+}
+
+/// A finger pressed on the cells, panning them once it moves far enough to
+/// no longer be a tap.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct TouchPan {
+    pub(super) finger: touch::Finger,
+    origin: Point,
+    last: Point,
+    is_panning: bool,
+}
+
+impl TouchPan {
+    /// The distance a finger moves before a tap becomes a pan.
+    const THRESHOLD: f32 = 8.0;
+
+    pub(super) fn new(finger: touch::Finger, position: Point) -> Self {
+        Self {
+            finger,
+            origin: position,
+            last: position,
+            is_panning: false,
+        }
+    }
+
+    /// Moves the finger to `position`, returning how far it moved since the
+    /// last pan once it is past the threshold.
+    pub(super) fn pan(&mut self, position: Point) -> Option<Vector> {
+        if !self.is_panning && self.origin.distance(position) < Self::THRESHOLD {
+            return None;
+        }
+
+        self.is_panning = true;
+        let delta = position - self.last;
+        self.last = position;
+
+        Some(delta)
+    }
+}
+
+/// The eased wheel scrolling of the cells towards a target offset.
+#[derive(Debug, Clone)]
+pub(super) struct SmoothScroll {
+    x: Animated<f32, Instant>,
+    y: Animated<f32, Instant>,
+    pub(super) target: Vector,
+    /// The offset the cells were last scrolled to by the animation.
+    pub(super) applied: Vector,
+}
+
+impl SmoothScroll {
+    pub(super) fn new(offset: Vector, duration_ms: f32, easing: Easing) -> Self {
+        let animated = |value: f32| Animated::new(value).duration(duration_ms).easing(easing);
+
+        Self {
+            x: animated(offset.x),
+            y: animated(offset.y),
+            target: offset,
+            applied: offset,
+        }
+    }
+
+    /// Starts easing towards `target` from wherever the animation is at `now`.
+    pub(super) fn scroll_to(&mut self, target: Vector, now: Instant) {
+        self.x.transition(target.x, now);
+        self.y.transition(target.y, now);
+        self.target = target;
+    }
+
+    /// Returns the offset of the animation at `now`, recording it as applied.
+    pub(super) fn advance(&mut self, now: Instant) -> Vector {
+        self.applied = Vector::new(
+            self.x.animate(std::convert::identity, now),
+            self.y.animate(std::convert::identity, now),
+        );
+
+        self.applied
+    }
+
+    pub(super) fn in_progress(&self, now: Instant) -> bool {
+        self.x.in_progress(now) || self.y.in_progress(now)
+    }
+}
+
+/// A scrollbar of the cells viewport.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Scrollbar {
+    pub(super) track: Rectangle,
+    pub(super) thumb: Rectangle,
+    pub(super) is_vertical: bool,
+    /// The length of the scrollable content.
+    content: f32,
+}
+
+impl Scrollbar {
+    /// Returns the vertical [`Scrollbar`] of `viewport` if `content` overflows
+    /// it.
+    pub(super) fn vertical(
+        viewport: Rectangle,
+        content: f32,
+        offset: f32,
+        width: f32,
+    ) -> Option<Self> {
+        if content <= viewport.height || width <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle::new(
+            Point::new(viewport.x + viewport.width - width, viewport.y),
+            Size::new(width, viewport.height),
+        );
+
+        let length = (track.height * viewport.height / content)
+            .max(width * 2.0)
+            .min(track.height);
+        let ratio = (-offset / (content - viewport.height)).clamp(0.0, 1.0);
+
+        let thumb = Rectangle::new(
+            Point::new(track.x, track.y + ratio * (track.height - length)),
+            Size::new(width, length),
+        );
+
+        Some(Self {
+            track,
+            thumb,
+            is_vertical: true,
+            content,
+        })
+    }
+
+    /// Returns the horizontal [`Scrollbar`] of `viewport` if `content`
+    /// overflows it.
+    pub(super) fn horizontal(
+        viewport: Rectangle,
+        content: f32,
+        offset: f32,
+        width: f32,
+    ) -> Option<Self> {
+        if content <= viewport.width || width <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle::new(
+            Point::new(viewport.x, viewport.y + viewport.height - width),
+            Size::new(viewport.width, width),
+        );
+
+        let length = (track.width * viewport.width / content)
+            .max(width * 2.0)
+            .min(track.width);
+        let ratio = (-offset / (content - viewport.width)).clamp(0.0, 1.0);
+
+        let thumb = Rectangle::new(
+            Point::new(track.x + ratio * (track.width - length), track.y),
+            Size::new(length, width),
+        );
+
+        Some(Self {
+            track,
+            thumb,
+            is_vertical: false,
+            content,
+        })
+    }
+
+    /// Returns the position of `point` along the [`Scrollbar`].
+    pub(super) fn position(&self, point: Point) -> f32 {
+        if self.is_vertical {
+            point.y
+        } else {
+            point.x
+        }
+    }
+
+    /// Returns the position of the start of the thumb.
+    pub(super) fn thumb_start(&self) -> f32 {
+        self.position(self.thumb.position())
+    }
+
+    /// Returns the scroll offset which puts the start of the thumb at
+    /// `position`.
+    pub(super) fn offset(&self, position: f32) -> f32 {
+        let (start, track, thumb) = if self.is_vertical {
+            (self.track.y, self.track.height, self.thumb.height)
+        } else {
+            (self.track.x, self.track.width, self.thumb.width)
+        };
+
+        if track <= thumb {
+            return 0.0;
+        }
+
+        let ratio = ((position - start) / (track - thumb)).clamp(0.0, 1.0);
+
+        -ratio * (self.content - track)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 /// A key press.
 pub struct KeyPress {
@@ -761,11 +1719,17 @@ pub enum Action {
         column: usize,
         row: usize,
     },
-    /// A cell selection
+    /// A cell selection, in absolute data rows
     Selection(Selection),
-    /// A page change
+    /// A clearing of the [`Selection`], as on Escape or once its last cell is
+    /// toggled off.
+    SelectionClear,
+    /// A page change. Both pages start from `1`.
     PageChange { previous: usize, current: usize },
-    /// A column and/or row resizing
+    /// A page limit chosen from the page size selector.
+    PageLimit(usize),
+    /// A column and/or row resizing. The `row` and `column` are data indices,
+    /// excluding the numbering column and headers row.
     Resize {
         direction: ResizeDirection,
         size: Size,
@@ -774,6 +1738,41 @@ pub enum Action {
     },
     /// A [`Selection`] movement.
     MoveSelection(Motion),
+    /// A change of the query in the search input. The application filters
+    /// its rows, with an empty query restoring them all.
+    Search(String),
+    /// A sort requested from a header. A `None` order returns the column to
+    /// the unsorted data order.
+    Sort {
+        column: usize,
+        order: Option<SortOrder>,
+    },
+    /// A right click on a [`CellTarget`], at the cursor `position`. The target
+    /// is selected first unless it already was.
+    RightClick { target: CellTarget, position: Point },
+    /// A double click on a [`CellTarget`], published whether or not it
+    /// starts an edit.
+    DoubleClick(CellTarget),
+    /// A change of the `(row, column)` of the data cell under the cursor,
+    /// `None` once it leaves the cells. Not published while dragging.
+    Hover(Option<(usize, usize)>),
+    /// A click on the kind shown under the header of a column.
+    KindClick(usize),
+    /// An insertion of a row or column at `index`, shifting the selected ones
+    /// and those after them.
+    Insert { axis: Axis, index: usize },
+    /// A removal of the selected rows or columns.
+    Remove {
+        axis: Axis,
+        range: RangeInclusive<usize>,
+    },
+    /// An edit of a header or data cell discarded without being submitted,
+    /// as on Escape, a click away or a page change. Values applied from its
+    /// [`Action::HeaderInput`] or [`Action::CellInput`] are best reverted.
+    EditCancel(CellTarget),
+    /// A change of the value or cursor of the header or data cell being
+    /// edited, starting with its first value.
+    EditState(EditState),
 }
 
 impl Action {
@@ -820,7 +1819,10 @@ pub trait RawTable {
 
     /// Returns the value at the specified row and column in the [RawTable],
     /// if it exists.
-    fn cell(&self, row: usize, column: usize) -> Option<String>;
+    ///
+    /// Values already stored as text are best borrowed, as every layout of
+    /// the [`Table`] reads the cells of its page.
+    fn cell(&self, row: usize, column: usize) -> Option<Cow<'_, str>>;
 
     /// Returns true if the [`RawTable`] has no cells.
     fn is_empty(&self) -> bool;
@@ -828,6 +1830,290 @@ pub trait RawTable {
     /// Returns `true` if the `character` is accepted by the specified `ColumnKind`.
     fn column_filter(&self, kind: &Self::ColumnKind, character: char) -> bool;
 
+    /// Returns `true` if the `value` of a cell being edited is valid for the
+    /// specified `ColumnKind`.
+    ///
+    /// Checked after every insertion, so `value` may be a partially typed
+    /// value like `"-"` or `"3."`. Characters are only validated after being
+    /// accepted by [`RawTable::column_filter`].
+    fn column_validate(&self, _kind: &Self::ColumnKind, _value: &str) -> bool {
+        true
+    }
+
+    /// Validates the `value` of a cell being edited for the specified
+    /// `ColumnKind`, returning the error shown in its place if invalid.
+    ///
+    /// Checked after every edit, on the value as it would be submitted. A
+    /// cell with an error is styled apart and cannot be submitted.
+    fn validate(&self, _kind: &Self::ColumnKind, _value: &str) -> Result<(), String> {
+        Ok(())
+    }
+
     /// Returns the [`Horizontal`] column alignment for the specified `ColumnKind`.
     fn kind_alignment(&self, kind: &Self::ColumnKind) -> Horizontal;
+
+    /// Returns the full description of a `kind`, shown as a tooltip over the
+    /// kind under a header. Defaults to no tooltip.
+    fn kind_description(&self, _kind: &Self::ColumnKind) -> Option<String> {
+        None
+    }
+
+    /// Returns `true` if the specified `ColumnKind` holds boolean values.
+    ///
+    /// The cells of boolean columns hold either `"true"` or `"false"`.
+    fn is_boolean(&self, _kind: &Self::ColumnKind) -> bool {
+        false
+    }
+
+    /// Returns the number shown in the numbering column for the data `row`.
+    ///
+    /// Filtered tables can return the row number in the unfiltered data.
+    fn row_number(&self, row: usize) -> usize {
+        row
+    }
+
+    /// Returns `true` if the specified `ColumnKind` holds numbers.
+    ///
+    /// The cells of numeric columns are shown with the [`NumericFormat`] of
+    /// the [`Table`].
+    fn is_numeric(&self, _kind: &Self::ColumnKind) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI_BYTE: &str = "héllo 😀 世界";
+
+    struct Grid(Vec<Vec<&'static str>>);
+
+    impl RawTable for Grid {
+        type ColumnKind = &'static str;
+
+        fn height(&self) -> usize {
+            self.0.len()
+        }
+
+        fn width(&self) -> usize {
+            self.0.first().map_or(0, Vec::len)
+        }
+
+        fn column_header(&self, index: usize) -> Option<String> {
+            Some(format!("Column {index}"))
+        }
+
+        fn column_kind(&self, _index: usize) -> Option<Self::ColumnKind> {
+            Some("Text")
+        }
+
+        fn cell(&self, row: usize, column: usize) -> Option<Cow<'_, str>> {
+            self.0
+                .get(row)?
+                .get(column)
+                .map(|value| Cow::Borrowed(*value))
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn column_filter(&self, _kind: &Self::ColumnKind, _character: char) -> bool {
+            true
+        }
+
+        fn kind_alignment(&self, _kind: &Self::ColumnKind) -> Horizontal {
+            Horizontal::Left
+        }
+    }
+
+    fn scattered(cells: &[(usize, usize)]) -> Selection {
+        Selection::Scattered {
+            cells: cells.iter().copied().collect(),
+            last: cells.last().copied().unwrap_or_default(),
+        }
+    }
+
+    #[test]
+    fn block_selection_inspection() {
+        let block = Selection::column_range(1..=3, 4..=5);
+
+        assert_eq!(block.len(), 6);
+        assert_eq!(block.rows().collect::<Vec<_>>(), [4, 5]);
+        assert_eq!(block.columns().collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(!block.is_single_cell());
+
+        let single = Selection::new(7, 2);
+
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.rows().collect::<Vec<_>>(), [7]);
+        assert_eq!(single.columns().collect::<Vec<_>>(), [2]);
+        assert!(single.is_single_cell());
+    }
+
+    #[test]
+    fn scattered_selection_inspection() {
+        let cells = scattered(&[(5, 3), (1, 0), (5, 0), (9, 7)]);
+
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells.rows().collect::<Vec<_>>(), [1, 5, 9]);
+        assert_eq!(cells.columns().collect::<Vec<_>>(), [0, 3, 7]);
+        assert!(!cells.is_single_cell());
+
+        let single = scattered(&[(2, 4)]);
+
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.rows().collect::<Vec<_>>(), [2]);
+        assert_eq!(single.columns().collect::<Vec<_>>(), [4]);
+        assert!(single.is_single_cell());
+
+        let empty = scattered(&[]);
+
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.columns().count(), 0);
+        assert!(!empty.is_single_cell());
+    }
+
+    #[test]
+    fn offsets_never_split_characters() {
+        for index in 0..=char_len(MULTI_BYTE) + 1 {
+            assert!(MULTI_BYTE.is_char_boundary(byte_offset(MULTI_BYTE, index)));
+        }
+
+        for start in 0..=char_len(MULTI_BYTE) {
+            for end in start..=char_len(MULTI_BYTE) {
+                let range = char_range(MULTI_BYTE, start, end);
+
+                assert!(MULTI_BYTE.is_char_boundary(range.start));
+                assert!(MULTI_BYTE.is_char_boundary(range.end));
+                assert_eq!(MULTI_BYTE[range].chars().count(), end - start);
+            }
+        }
+    }
+
+    #[test]
+    fn insertion_next_to_multi_byte_characters() {
+        let mut value = String::from("é😀日");
+        let mut cursor = Cursor::default();
+
+        for (index, expected) in [(1, "éx😀日"), (3, "éx😀x日"), (5, "éx😀x日x")] {
+            cursor.move_to(index);
+            Editor::new(&mut value, &mut cursor).insert('x');
+
+            assert_eq!(value, expected);
+            assert_eq!(cursor.start(&value), index + 1);
+        }
+    }
+
+    #[test]
+    fn deletion_next_to_multi_byte_characters() {
+        let mut value = String::from("a😀b日本");
+        let mut cursor = Cursor::default();
+
+        cursor.move_to(2);
+        Editor::new(&mut value, &mut cursor).backspace();
+        assert_eq!(value, "ab日本");
+        assert_eq!(cursor.start(&value), 1);
+
+        cursor.move_to(3);
+        Editor::new(&mut value, &mut cursor).delete();
+        assert_eq!(value, "ab日");
+        assert_eq!(cursor.start(&value), 3);
+
+        Editor::new(&mut value, &mut cursor).backspace();
+        assert_eq!(value, "ab");
+    }
+
+    #[test]
+    fn selections_across_multi_byte_characters() {
+        let mut value = String::from(MULTI_BYTE);
+        let mut cursor = Cursor::default();
+
+        cursor.select_range(1, 8);
+        assert_eq!(cursor.selected(&value), Some("éllo 😀 "));
+
+        Editor::new(&mut value, &mut cursor).insert('x');
+        assert_eq!(value, "hx世界");
+        assert_eq!(cursor.start(&value), 2);
+
+        cursor.select_range(4, 1);
+        assert_eq!(cursor.selected(&value), Some("x世界"));
+
+        Editor::new(&mut value, &mut cursor).backspace();
+        assert_eq!(value, "h");
+    }
+
+    #[test]
+    fn word_jumps_keep_underscores_in_words() {
+        let value = "foo_bar baz";
+
+        assert_eq!(next_word(value, 0), 7);
+        assert_eq!(next_word(value, 7), 11);
+        assert_eq!(previous_word(value, 11), 8);
+        assert_eq!(previous_word(value, 8), 0);
+    }
+
+    #[test]
+    fn word_jumps_skip_punctuation() {
+        let value = "a.b";
+
+        assert_eq!(next_word(value, 0), 1);
+        assert_eq!(next_word(value, 1), 3);
+        assert_eq!(previous_word(value, 3), 2);
+        assert_eq!(previous_word(value, 2), 0);
+    }
+
+    #[test]
+    fn word_jumps_skip_surrounding_whitespace() {
+        let value = "  foo  ";
+
+        assert_eq!(next_word(value, 0), 5);
+        assert_eq!(next_word(value, 5), 7);
+        assert_eq!(previous_word(value, 7), 2);
+        assert_eq!(previous_word(value, 2), 0);
+    }
+
+    #[test]
+    fn word_jumps_in_empty_values() {
+        assert_eq!(next_word("", 0), 0);
+        assert_eq!(previous_word("", 0), 0);
+        assert_eq!(next_word("", 3), 0);
+        assert_eq!(previous_word("", 3), 0);
+    }
+
+    #[test]
+    fn page_changes_start_from_one() {
+        assert!(matches!(
+            Action::page(0, 2),
+            Action::PageChange {
+                previous: 1,
+                current: 3
+            }
+        ));
+    }
+
+    #[test]
+    fn copies_paste_back_unchanged() {
+        let grid = Grid(vec![vec!["5\" pipe", "\"quoted\""], vec!["a,b", ""]]);
+        let selection = Selection::column_range(0..=1, 0..=1);
+
+        let pasted = tab_separated(&selection.copy(&grid))
+            .map(Iterator::collect)
+            .collect::<Vec<Vec<&str>>>();
+
+        assert_eq!(pasted, grid.0);
+    }
+
+    #[test]
+    fn renders_quote_special_values() {
+        let grid = Grid(vec![vec!["5\" pipe", "a\tb"], vec!["a,b", "plain"]]);
+        let selection = Selection::column_range(0..=1, 0..=1);
+
+        assert_eq!(
+            selection.render(&grid, ','),
+            "\"5\"\" pipe\",a\tb\n\"a,b\",plain"
+        );
+    }
 }
@@ -30,6 +30,8 @@ where
     gap: f32,
     easing: Easing,
     duration: f32,
+    expand_animation: Option<(f32, Easing)>,
+    collapse_animation: Option<(f32, Easing)>,
     class: Theme::Class<'a>,
     collapsed: bool,
     collapse_on_click: bool,
@@ -65,6 +67,8 @@ where
             padding: [3, 3].into(),
             easing: Easing::EaseInOut,
             duration: 250.0,
+            expand_animation: None,
+            collapse_animation: None,
             collapsed: false,
             on_action: None,
             class: Theme::default(),
@@ -132,6 +136,26 @@ where
         self
     }
 
+    /// Sets the duration in milliseconds and the [`Easing`] function used when
+    /// the [`Tree`] expands.
+    ///
+    /// Defaults to the values set by [`Tree::animation_duration`] and
+    /// [`Tree::animation_easing`].
+    pub fn expand_animation(mut self, duration_ms: f32, easing: Easing) -> Self {
+        self.expand_animation = Some((duration_ms, easing));
+        self
+    }
+
+    /// Sets the duration in milliseconds and the [`Easing`] function used when
+    /// the [`Tree`] collapses.
+    ///
+    /// Defaults to the values set by [`Tree::animation_duration`] and
+    /// [`Tree::animation_easing`].
+    pub fn collapse_animation(mut self, duration_ms: f32, easing: Easing) -> Self {
+        self.collapse_animation = Some((duration_ms, easing));
+        self
+    }
+
     /// Sets the message that should be produced when some action is performed
     /// in the [`Tree`].
     pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
@@ -542,9 +566,20 @@ where
             Event::Window(window::Event::RedrawRequested(now)) if state.is_dirty => {
                 state.now = *now;
 
-                state
-                    .animation
-                    .transition(f32::from(state.collapsed), Instant::now());
+                let target = f32::from(state.collapsed);
+
+                if state.animation.value != target {
+                    let (duration, easing) = if state.collapsed {
+                        self.collapse_animation
+                    } else {
+                        self.expand_animation
+                    }
+                    .unwrap_or((self.duration, self.easing));
+
+                    state.animation = state.animation.clone().duration(duration).easing(easing);
+                }
+
+                state.animation.transition(target, Instant::now());
 
                 shell.invalidate_layout();
 